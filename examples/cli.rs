@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand};
-use currencyapi::{CurrencyCode, latest, RateLimitIgnore, Rates};
+use currencyapi::{CurrencyCode, latest, RateLimitIgnore, Rates, RateTable};
 
 #[derive(Parser, Debug)]
 pub struct Cli {
@@ -18,7 +18,7 @@ pub enum CliCommand {
 	Convert {
 		from: CurrencyCode,
 		to: CurrencyCode,
-		amount: f64,
+		amount: Rate,
 	},
 }
 
@@ -34,19 +34,35 @@ async fn main() {
 	match cli.command {
 		CliCommand::Rates { base, currencies } => {
 			let mut rates = Rates::<Rate>::new();
-			let request = request.base_currency(base).currencies(currencies).build();
+			let request = request.base_currency(base).currencies(currencies).build().unwrap();
 			let metadata = rates
 				.fetch_latest::<DateTime<Utc>, RateLimitIgnore>(&client, request)
 				.await
 				.unwrap();
 			println!("Fetched {} rates as of {}", rates.len(), metadata.last_updated_at);
-			for (currency, value) in rates.iter() { println!("{currency} {value}"); }
+			println!("{rates:#}");
 		}
 		CliCommand::Convert { from, to, amount } => {
 			let mut rates = Rates::<Rate>::new();
-			let request = request.currencies([from,to]).build();
+			let request = request.currencies([from,to]).build().unwrap();
 			rates.fetch_latest::<DateTime<Utc>, RateLimitIgnore>(&client, request).await.unwrap();
-			println!("{} {} = {} {}", amount, from, rates.convert(&amount.try_into().unwrap(), from, to).unwrap(), to);
+			match rates.try_convert(&amount, from, to) {
+				Ok(converted) => println!("{amount} {from} = {converted} {to}"),
+				Err(e) => eprintln!("Failed to convert {amount} {from} to {to}: {e}"),
+			}
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use currencyapi::currency::*;
+
+	#[test]
+	fn test_parse_lowercase() {
+		let cli = Cli::try_parse_from(["cli", "token", "convert", "usd", "Eur", "1"]).unwrap();
+		assert!(matches!(cli.command, CliCommand::Convert { from, to, .. } if from == USD && to == EUR));
+		assert!(Cli::try_parse_from(["cli", "token", "convert", "us1", "eur", "1"]).is_err());
+	}
+}
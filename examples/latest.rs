@@ -0,0 +1,14 @@
+use currencyapi::Latest;
+
+#[tokio::main]
+async fn main() {
+	let token = std::env::args().nth(1).expect("usage: latest <TOKEN>");
+	let client = reqwest::Client::new();
+
+	let (rates, metadata) = Latest::new(token, None, std::iter::empty())
+		.send::<200>(&client)
+		.await
+		.unwrap();
+	println!("Fetched {} rates as of {}", rates.len(), metadata.last_updated_at);
+	for (currency, value) in rates.iter() { println!("{currency} {value}"); }
+}
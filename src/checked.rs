@@ -0,0 +1,81 @@
+//! [`CheckedRateOps`]
+
+/// An arithmetic error, see [`CheckedRateOps`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ArithmeticError {
+	/// Division by zero.
+	#[error("division by zero")]
+	DivisionByZero,
+	/// The result is out of the type's range, or for floats, isn't finite.
+	#[error("arithmetic overflow")]
+	Overflow,
+}
+
+/// Arithmetic that fails rather than panicking or producing a non-finite result.
+pub trait CheckedRateOps: Sized {
+	/// Multiplies by `rhs`.
+	fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+	/// Divides by `rhs`.
+	fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+}
+
+macro_rules! impl_float {
+	($($float:ty),*) => {$(
+		impl CheckedRateOps for $float {
+			fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+				let result = self * rhs;
+				if result.is_finite() { Ok(result) } else { Err(ArithmeticError::Overflow) }
+			}
+
+			fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+				if *rhs == 0. { return Err(ArithmeticError::DivisionByZero) }
+				let result = self / rhs;
+				if result.is_finite() { Ok(result) } else { Err(ArithmeticError::Overflow) }
+			}
+		}
+	)*};
+}
+impl_float!(f32, f64);
+
+#[cfg(feature = "rust_decimal")]
+impl CheckedRateOps for rust_decimal::Decimal {
+	fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+		self.checked_mul(*rhs).ok_or(ArithmeticError::Overflow)
+	}
+
+	fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+		if rhs.is_zero() { return Err(ArithmeticError::DivisionByZero) }
+		self.checked_div(*rhs).ok_or(ArithmeticError::Overflow)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{currency::*, ConvertError, Rates, RateTable};
+
+	#[test]
+	fn test_checked_float() {
+		assert_eq!(2.0f64.try_mul(&3.0), Ok(6.0));
+		assert_eq!(f64::MAX.try_mul(&2.0), Err(ArithmeticError::Overflow));
+		assert_eq!(1.0f32.try_div(&0.0), Err(ArithmeticError::DivisionByZero));
+		assert_eq!(f64::NAN.try_div(&1.0), Err(ArithmeticError::Overflow));
+
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.0), (ILS, 4.0)]).unwrap();
+		assert_eq!(rates.checked_convert(&2.0, USD, ILS), Ok(8.0));
+		assert_eq!(rates.checked_convert(&f64::MAX, USD, ILS), Err(ConvertError::Arithmetic(ArithmeticError::Overflow)));
+		assert_eq!(rates.checked_convert(&1.0, EUR, USD), Err(ConvertError::Arithmetic(ArithmeticError::DivisionByZero)));
+		assert_eq!(rates.checked_convert(&1.0, GBP, USD), Err(ConvertError::MissingFrom(GBP)));
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_checked_decimal() {
+		use rust_decimal::Decimal;
+		let rates = Rates::<Decimal, 3>::try_from_iter([(USD, Decimal::ONE), (EUR, Decimal::ZERO), (ILS, Decimal::from(4))]).unwrap();
+		assert_eq!(rates.checked_convert(&Decimal::TWO, USD, ILS), Ok(Decimal::from(8)));
+		assert_eq!(rates.checked_convert(&Decimal::MAX, USD, ILS), Err(ConvertError::Arithmetic(ArithmeticError::Overflow)));
+		assert_eq!(rates.checked_convert(&Decimal::ONE, EUR, USD), Err(ConvertError::Arithmetic(ArithmeticError::DivisionByZero)));
+		assert_eq!(rates.checked_convert(&Decimal::ONE, USD, JPY), Err(ConvertError::MissingTo(JPY)));
+	}
+}
@@ -0,0 +1,86 @@
+//! [`Client`] facade.
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+	latest::{self, AllCurrencies, Metadata},
+	rate_limit::RateLimitData,
+	url::{NoBaseCurrency, UrlPart},
+	CurrencyCode, Error, FromScientific, RateLimitIgnore, Rates, Token,
+};
+
+/// An API client, bundling the [API token](https://currencyapi.com/docs/#authentication-api-key-information)
+/// with an HTTP client.
+///
+/// Requests made through it are pre-populated with the token, for the lower-level API see the
+/// endpoint modules, e.g. [`latest`].
+#[derive(Debug, Clone)]
+pub struct Client {
+	token: Token<'static>,
+	http: reqwest::Client,
+}
+
+impl Client {
+	/// Creates a new [`Client`] with the given API token.
+	#[inline] pub fn new(token: impl Into<Token<'static>>) -> Self { Self::with_http_client(token, reqwest::Client::new()) }
+
+	/// Creates a new [`Client`] with the given API token and a preconfigured HTTP client.
+	#[inline] pub fn with_http_client(token: impl Into<Token<'static>>, http: reqwest::Client) -> Self {
+		Self { token: token.into(), http }
+	}
+
+	/// Gets the HTTP client.
+	#[inline] pub fn http_client(&self) -> &reqwest::Client { &self.http }
+
+	/// Starts a request to the [`latest`] endpoint.
+	#[inline] pub fn latest(&self) -> LatestBuilder<'_> {
+		LatestBuilder {
+			http: &self.http,
+			builder: latest::Builder::new(self.token.borrowed()),
+		}
+	}
+}
+
+/// A [`latest::Builder`] bound to a [`Client`].
+#[derive(Debug, Clone)]
+pub struct LatestBuilder<'a, Currencies = AllCurrencies, BaseCurrency = NoBaseCurrency> {
+	http: &'a reqwest::Client,
+	builder: latest::Builder<'a, Currencies, BaseCurrency>,
+}
+
+impl<'a, Currencies, BaseCurrency> LatestBuilder<'a, Currencies, BaseCurrency> {
+	/// Sets the [`currencies`](latest::Builder::currencies).
+	#[inline] pub fn currencies<CurrenciesNew>(self, currencies: CurrenciesNew) -> LatestBuilder<'a, CurrenciesNew, BaseCurrency> {
+		LatestBuilder { http: self.http, builder: self.builder.currencies(currencies) }
+	}
+
+	/// Sets the [`base_currency`](latest::Builder::base_currency).
+	#[inline] pub fn base_currency<BaseCurrencyNew>(self, base_currency: BaseCurrencyNew) -> LatestBuilder<'a, Currencies, crate::url::BaseCurrency<BaseCurrencyNew>> where crate::url::BaseCurrency<BaseCurrencyNew>: UrlPart {
+		LatestBuilder { http: self.http, builder: self.builder.base_currency(base_currency) }
+	}
+
+	/// Clears the [`base_currency`](latest::Builder::base_currency) parameter.
+	#[inline] pub fn base_currency_clear(self) -> LatestBuilder<'a, Currencies, NoBaseCurrency> {
+		LatestBuilder { http: self.http, builder: self.builder.base_currency_clear() }
+	}
+
+	/// Gets the underlying [`latest::Builder`].
+	#[inline] pub fn into_builder(self) -> latest::Builder<'a, Currencies, BaseCurrency> { self.builder }
+}
+
+impl<'a, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency: UrlPart> LatestBuilder<'a, Currencies, BaseCurrency> {
+	/// Fetches the rates into the given [`Rates`].
+	#[inline] pub async fn fetch_into<const N: usize, DateTime: FromStr, RATE: FromScientific<Error: fmt::Display>, RateLimit: for<'x> RateLimitData<'x>>(
+		self,
+		rates: &mut Rates<RATE, N>,
+	) -> Result<Metadata<DateTime, RateLimit>, Error> {
+		self.builder.build()?.send(rates, self.http).await
+	}
+
+	/// Fetches up to `N` rates.
+	pub async fn fetch<RATE: FromScientific<Error: fmt::Display>, const N: usize>(self) -> Result<(Rates<RATE, N>, Metadata<String>), Error> {
+		let mut rates = Rates::new();
+		let metadata = self.fetch_into::<N, String, RATE, RateLimitIgnore>(&mut rates).await?;
+		Ok((rates, metadata))
+	}
+}
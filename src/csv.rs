@@ -0,0 +1,98 @@
+//! CSV import and export of [`Rates`].
+
+use std::{fmt::Display, io, str::FromStr};
+
+use crate::{CurrencyCode, CurrencyError, Rates};
+
+/// An error reading [`Rates`] from CSV, see [`Rates::read_csv`].
+#[derive(Debug, thiserror::Error)]
+pub enum CsvError<E> {
+	/// Failed to read the input.
+	#[error("failed to read the CSV: {0}")]
+	Io(#[from] io::Error),
+	/// A line is not of the form `currency,rate`.
+	#[error("line {line}: expected `currency,rate`")]
+	Syntax {
+		/// The 1-based line number.
+		line: usize,
+	},
+	/// A line has an invalid currency code.
+	#[error("line {line}: {source}")]
+	Currency {
+		/// The 1-based line number.
+		line: usize,
+		/// The currency code error.
+		source: CurrencyError,
+	},
+	/// A line has an invalid rate.
+	#[error("line {line}: invalid rate: {error}")]
+	Rate {
+		/// The 1-based line number.
+		line: usize,
+		/// The rate parse error.
+		error: E,
+	},
+	/// A line has a new currency that doesn't fit in the [`Rates`].
+	#[error("line {line}: no capacity for the {currency} rate")]
+	Capacity {
+		/// The 1-based line number.
+		line: usize,
+		/// The rejected currency.
+		currency: CurrencyCode,
+	},
+}
+
+impl<const N: usize, RATE> Rates<RATE, N> {
+	/// Writes the rates in use as `currency,rate` lines, without a header.
+	pub fn write_csv(&self, mut writer: impl io::Write) -> io::Result<()> where RATE: Display {
+		for (currency, rate) in self.entries() {
+			writeln!(writer, "{currency},{rate}")?;
+		}
+		Ok(())
+	}
+
+	/// Reads rates from `currency,rate` lines, see [`Rates::write_csv`].
+	///
+	/// Blank lines are skipped, and a currency that appears more than once takes its last rate. Fails on the first
+	/// invalid line, or on a currency that doesn't fit, rather than silently dropping rates.
+	pub fn read_csv(reader: impl io::BufRead) -> Result<Self, CsvError<RATE::Err>> where RATE: FromStr {
+		let mut rates = Self::new();
+		for (i, line) in reader.lines().enumerate() {
+			let line_number = i + 1;
+			let line = line?;
+			let line = line.trim();
+			if line.is_empty() { continue }
+			let (currency, rate) = line.split_once(',').ok_or(CsvError::Syntax { line: line_number })?;
+			let currency = currency.trim().parse::<CurrencyCode>()
+				.map_err(|source| CsvError::Currency { line: line_number, source })?;
+			let rate = rate.trim().parse::<RATE>()
+				.map_err(|error| CsvError::Rate { line: line_number, error })?;
+			if rates.insert_or_update(currency, rate).is_err() {
+				return Err(CsvError::Capacity { line: line_number, currency });
+			}
+		}
+		rates.sort();
+		Ok(rates)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_csv() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap();
+		let mut csv = Vec::new();
+		rates.write_csv(&mut csv).unwrap();
+		assert_eq!(csv, b"USD,1\nEUR,0.9\nILS,3.1\n");
+		assert_eq!(Rates::<f64, 3>::read_csv(csv.as_slice()).unwrap(), rates);
+		assert_eq!(Rates::<f64, 2>::read_csv("USD, 1\n\nUSD,1.1\r\n".as_bytes()).unwrap().get(USD), Some(&1.1));
+
+		assert!(matches!(Rates::<f64, 3>::read_csv("USD,1\nEUR".as_bytes()), Err(CsvError::Syntax { line: 2 })));
+		assert!(matches!(Rates::<f64, 3>::read_csv("USD,1\nEURUSD,0.9".as_bytes()), Err(CsvError::Currency { line: 2, .. })));
+		assert!(matches!(Rates::<f64, 3>::read_csv("USD,one".as_bytes()), Err(CsvError::Rate { line: 1, .. })));
+		assert!(matches!(Rates::<f64, 2>::read_csv(csv.as_slice()), Err(CsvError::Capacity { line: 3, currency }) if currency == ILS));
+	}
+}
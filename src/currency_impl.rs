@@ -31,10 +31,13 @@ pub struct CurrencyCode {
 }
 
 impl CurrencyCode {
+	/// Gets the code as a big-endian number, so that comparing them compares the codes lexicographically, and so that
+	/// it is the same on every platform.
 	const fn as_u64(self) -> u64 {
-		unsafe {
-			*(&self as *const Self as *const u8 as *const u64)
-		}
+		u64::from_be_bytes(unsafe {
+			// SAFETY: the code is 8 initialized bytes.
+			mem::transmute::<Self, [u8; 8]>(self)
+		})
 	}
 }
 
@@ -47,19 +50,45 @@ impl PartialEq for CurrencyCode {
 	#[inline] fn eq(&self, other: &Self) -> bool { self.as_u64() == other.as_u64() }
 } impl Eq for CurrencyCode {}
 
+/// Compares the code to a string, case-sensitively since codes are canonically uppercase, e.g. `USD == "USD"` but
+/// `USD != "usd"`.
+impl PartialEq<str> for CurrencyCode {
+	#[inline] fn eq(&self, other: &str) -> bool { AsRef::<[u8]>::as_ref(self) == other.as_bytes() }
+}
+
+/// See [`PartialEq<str>`](#impl-PartialEq<str>-for-CurrencyCode).
+impl PartialEq<&str> for CurrencyCode {
+	#[inline] fn eq(&self, other: &&str) -> bool { self == *other }
+}
+
+/// See [`PartialEq<str>`](#impl-PartialEq<str>-for-CurrencyCode).
+impl PartialEq<CurrencyCode> for str {
+	#[inline] fn eq(&self, other: &CurrencyCode) -> bool { other == self }
+}
+
+/// See [`PartialEq<str>`](#impl-PartialEq<str>-for-CurrencyCode).
+impl PartialEq<CurrencyCode> for &str {
+	#[inline] fn eq(&self, other: &CurrencyCode) -> bool { other == *self }
+}
+
 impl Hash for CurrencyCode {
 	#[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.as_u64().hash(state) }
 }
 
 impl PartialOrd for CurrencyCode {
-	#[inline] fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-		self.as_u64().partial_cmp(&other.as_u64())
-	}
+	#[inline] fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
 }
 
+/// Orders the codes alphabetically, e.g. `EUR < GBP < USD` and `US < USD`.
 impl Ord for CurrencyCode {
-	#[inline] fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-		self.as_u64().cmp(&other.as_u64())
+	#[inline] fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.const_cmp(*other) }
+}
+
+impl CurrencyCode {
+	/// [`Ord::cmp`], usable in const contexts.
+	pub(crate) const fn const_cmp(self, other: Self) -> std::cmp::Ordering {
+		let (a, b) = (self.as_u64(), other.as_u64());
+		if a < b { std::cmp::Ordering::Less } else if a > b { std::cmp::Ordering::Greater } else { std::cmp::Ordering::Equal }
 	}
 }
 
@@ -96,21 +125,53 @@ impl CurrencyCode {
 	}
 }
 
-impl TryFrom<&[u8]> for CurrencyCode {
-	type Error = Error;
-
-	fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-		let len = value.len();
+impl CurrencyCode {
+	/// Parses a currency code, like [`FromStr`], but usable in const contexts.
+	///
+	/// ASCII letters are case-insensitive, e.g. `usd` parses to [`USD`](list::USD).
+	///
+	/// ```
+	/// # use currencyapi::CurrencyCode;
+	/// const MYCOIN: CurrencyCode = match CurrencyCode::parse(b"MYC") {
+	///     Ok(code) => code,
+	///     Err(_) => panic!("invalid currency code"),
+	/// };
+	/// ```
+	pub const fn parse(code: &[u8]) -> Result<Self, Error> {
+		let len = code.len();
 		if len < CURRENCY_LEN_MIN { return Err(Error::TooShort); }
 		if len > CURRENCY_LEN_MAX { return Err(Error::TooLong); }
-		let bad_char = value[..CURRENCY_LEN_MIN].iter().find(|&&c| !c.is_ascii_uppercase())
-			.and(value[CURRENCY_LEN_MIN..].iter().find(|&&c| !c.is_ascii_uppercase() && c != 0))
-			.copied();
-		if let Some(bad_char) = bad_char { return Err(Error::InvalidCharacter(bad_char)); }
-		unsafe { Ok(Self::new_unchecked(value)) }
+		let mut buf = [0u8; CURRENCY_LEN_MAX];
+		// Whether the code ended and the rest must be zero padding.
+		let mut padded = false;
+		let mut i = 0;
+		while i < len {
+			let c = code[i].to_ascii_uppercase();
+			if c == 0 && i >= CURRENCY_LEN_MIN { padded = true; }
+			else if padded || !c.is_ascii_uppercase() { return Err(Error::InvalidCharacter(c)); }
+			buf[i] = c;
+			i += 1;
+		}
+		// SAFETY: validated above.
+		Ok(unsafe { Self::from_array_unchecked(buf) })
+	}
+}
+
+impl CurrencyCode {
+	/// Parses a currency code, like [`FromStr`], that is also [known](list::ARRAY).
+	///
+	/// Useful to fail fast on typos (e.g. `USE`) rather than on the API request.
+	pub fn from_str_known(s: &str) -> Result<Self, Error> {
+		let code = s.parse()?;
+		if list::ARRAY.contains(&code) { Ok(code) } else { Err(Error::Unknown(code)) }
 	}
 }
 
+impl TryFrom<&[u8]> for CurrencyCode {
+	type Error = Error;
+	#[inline] fn try_from(value: &[u8]) -> Result<Self, Self::Error> { Self::parse(value) }
+}
+
 impl FromStr for CurrencyCode {
 	type Err = Error;
 	#[inline] fn from_str(s: &str) -> Result<Self, Self::Err> { <Self as TryFrom<&[u8]>>::try_from(s.as_ref()) }
@@ -147,7 +208,7 @@ impl Display for CurrencyCode {
 
 impl Serialize for CurrencyCode {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-		<Self as AsRef<str>>::as_ref(&self).serialize(serializer)
+		<Self as AsRef<str>>::as_ref(self).serialize(serializer)
 	}
 }
 
@@ -173,7 +234,7 @@ impl<'de> Deserialize<'de> for CurrencyCode {
 
 /// Invalid currency code error.
 ///
-/// Valid currency codes are three uppercase alpha ASCII characters.
+/// Valid currency codes are 2 to 5 ASCII letters.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
 	/// The currency code is too short.
@@ -185,6 +246,9 @@ pub enum Error {
 	/// The currency code has an invalid character.
 	#[error("invalid currency code character ({0:?})")]
 	InvalidCharacter(u8),
+	/// The currency code is valid but not [known](list::ARRAY), see [`CurrencyCode::from_str_known`].
+	#[error("unknown currency code {0}")]
+	Unknown(CurrencyCode),
 }
 
 pub mod list {
@@ -198,7 +262,7 @@ pub mod list {
 	/// # Safety
 	/// Ensure all arguments consist of only uppercase alpha characters.
 	macro_rules! unsafe_define_currencies {
-		($from_fn:expr, $($currency:ident),*) => {
+		($($currency:ident),*) => {
 			$(
 				#[doc=concat!("The [", stringify!($currency), "](https://www.google.com/search?q=USD+to+", stringify!($currency), ") currency code.")]
 				pub const $currency: crate::CurrencyCode = unsafe { crate::CurrencyCode::from_array_unchecked(*bstringify::bstringify!($currency)) };
@@ -227,12 +291,31 @@ pub mod list {
 	unsafe_define_currencies!(
 		ADA, AED, AFN, ALL, AMD, ANG, AOA, ARB, ARS, AUD, AVAX, AWG, AZN, BAM, BBD, BDT, BGN, BHD, BIF, BMD, BNB, BND, BOB, BRL, BSD, BTC, BTN, BUSD, BWP, BYN, BYR, BZD, CAD, CDF, CHF, CLF, CLP, CNY, COP, CRC, CUC, CUP, CVE, CZK, DAI, DJF, DKK, DOP, DOT, DZD, EGP, ERN, ETB, ETH, EUR, FJD, FKP, GBP, GEL, GGP, GHS, GIP, GMD, GNF, GTQ, GYD, HKD, HNL, HRK, HTG, HUF, IDR, ILS, IMP, INR, IQD, IRR, ISK, JEP, JMD, JOD, JPY, KES, KGS, KHR, KMF, KPW, KRW, KWD, KYD, KZT, LAK, LBP, LKR, LRD, LSL, LTC, LTL, LVL, LYD, MAD, MATIC, MDL, MGA, MKD, MMK, MNT, MOP, MRO, MUR, MVR, MWK, MXN, MYR, MZN, NAD, NGN, NIO, NOK, NPR, NZD, OMR, OP, PAB, PEN, PGK, PHP, PKR, PLN, PYG, QAR, RON, RSD, RUB, RWF, SAR, SBD, SCR, SDG, SEK, SGD, SHP, SLL, SOL, SOS, SRD, STD, SVC, SYP, SZL, THB, TJS, TMT, TND, TOP, TRY, TTD, TWD, TZS, UAH, UGX, USD, USDC, USDT, UYU, UZS, VEF, VND, VUV, WST, XAF, XAG, XAU, XCD, XDR, XOF, XPD, XPF, XPT, XRP, YER, ZAR, ZMK, ZMW, ZWL
 	);
+
+	/// Gets all the currencies defined in this module, see [`ARRAY`].
+	#[inline] pub const fn all() -> &'static [crate::CurrencyCode] { &ARRAY }
+
+	/// Iterates over all the currencies defined in this module, see [`ARRAY`].
+	#[inline] pub fn iter() -> impl Iterator<Item = crate::CurrencyCode> { ARRAY.into_iter() }
+
+	/// Gets the currency of the given [name](crate::CurrencyInfo::name), e.g. "Euro" for [`EUR`].
+	///
+	/// The match is exact up to case and whitespace (e.g. " united states  dollar" matches [`USD`]), not fuzzy.
+	pub fn from_name(name: &str) -> Option<crate::CurrencyCode> {
+		fn normalized(name: &str) -> impl Iterator<Item = char> + '_ {
+			name.split_whitespace().flat_map(|word| std::iter::once(' ').chain(word.chars().flat_map(char::to_lowercase)))
+		}
+		crate::currency_info::INFO.iter()
+			.find(|(_, info)| normalized(info.name).eq(normalized(name)))
+			.map(|&(currency, _)| currency)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[allow(clippy::useless_nonzero_new_unchecked)]
 	const AVAX_MANUAL: CurrencyCode = CurrencyCode {
 		code_head: unsafe { [
 			NonZeroU8::new_unchecked(b'A'),
@@ -318,6 +401,123 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_parse_case_insensitive() {
+		for code in ["usd", "Usd", "uSd", "USD"] {
+			assert_eq!(code.parse::<CurrencyCode>().unwrap(), crate::currency::USD, "{code}");
+		}
+		assert_eq!(CurrencyCode::try_from(b"avax".as_slice()).unwrap(), crate::currency::AVAX);
+	}
+
+	#[test]
+	fn test_parse_invalid_char() {
+		for (code, bad) in [("E1", b'1'), ("-SD", b'-'), ("US ", b' '), ("EUR1", b'1')] {
+			match code.parse::<CurrencyCode>() {
+				Err(Error::InvalidCharacter(c)) => assert_eq!(c, bad, "{code}"),
+				_ => panic!("{code}"),
+			}
+		}
+	}
+
+	#[test]
+	fn test_from_str_known() {
+		assert_eq!(CurrencyCode::from_str_known("USD").unwrap(), crate::currency::USD);
+		assert_eq!(CurrencyCode::from_str_known("avax").unwrap(), crate::currency::AVAX);
+		match CurrencyCode::from_str_known("USE") {
+			Err(Error::Unknown(code)) => assert_eq!(code, "USE"),
+			_ => panic!(),
+		}
+		assert!(matches!(CurrencyCode::from_str_known("U"), Err(Error::TooShort)));
+	}
+
+	#[test]
+	fn test_parse_exhaustive() {
+		for code in ["OP", "USD", "AVAX", "MATIC"] {
+			let expected = code.parse::<CurrencyCode>().unwrap();
+			assert_eq!(<CurrencyCode as AsRef<str>>::as_ref(&expected), code);
+			for i in 0..code.len() {
+				// mixed case
+				let mut mixed = code.as_bytes().to_vec();
+				mixed[i] = mixed[i].to_ascii_lowercase();
+				assert_eq!(CurrencyCode::try_from(mixed.as_slice()).unwrap(), expected, "{mixed:?}");
+				// embedded digit
+				let mut digit = code.as_bytes().to_vec();
+				digit[i] = b'7';
+				assert!(matches!(CurrencyCode::try_from(digit.as_slice()), Err(Error::InvalidCharacter(b'7'))), "{digit:?}");
+				// NUL
+				let mut nul = code.as_bytes().to_vec();
+				nul[i] = 0;
+				assert!(matches!(CurrencyCode::try_from(nul.as_slice()), Err(Error::InvalidCharacter(_))) == (i + 1 < code.len() || i < CURRENCY_LEN_MIN), "{nul:?}");
+			}
+			// zero padding
+			let mut padded = code.as_bytes().to_vec();
+			padded.resize(CURRENCY_LEN_MAX, 0);
+			assert_eq!(CurrencyCode::try_from(padded.as_slice()).unwrap(), expected);
+		}
+		for interior_nul in [&b"US\0D"[..], b"AV\0AX", b"MAT\0C", b"USD\0X"] {
+			assert!(matches!(CurrencyCode::try_from(interior_nul), Err(Error::InvalidCharacter(_))), "{interior_nul:?}");
+		}
+	}
+
+	#[test]
+	fn test_parse_const() {
+		const MYCOIN: CurrencyCode = match CurrencyCode::parse(b"MYC") {
+			Ok(code) => code,
+			Err(_) => panic!(),
+		};
+		assert_eq!(MYCOIN, "MYC".parse::<CurrencyCode>().unwrap());
+		assert!(matches!(CurrencyCode::parse(b"MYCOIN"), Err(Error::TooLong)));
+	}
+
+	#[test]
+	fn test_eq_str() {
+		use crate::currency::*;
+		assert_eq!(USD, "USD");
+		assert_eq!("USD", USD);
+		assert_eq!(AVAX, *"AVAX");
+		assert_eq!(*"AVAX", AVAX);
+		assert!(USD != "usd" && USD != "US" && USD != "USDT" && USD != "");
+		assert!([EUR, USD].iter().any(|&code| code == "USD"));
+	}
+
+	#[test]
+	fn test_ord() {
+		use crate::currency::{self, *};
+		let mut codes = vec![USD, GBP, EUR];
+		codes.sort();
+		assert_eq!(codes, [EUR, GBP, USD]);
+		assert!("US".parse::<CurrencyCode>().unwrap() < USD && USD < USDC && ADA < AVAX);
+
+		let mut codes = currency::all().to_vec();
+		codes.extend(["AB", "ZZ", "AAAAA", "ZZZZZ", "USDZ"].map(|code| code.parse::<CurrencyCode>().unwrap()));
+		for a in &codes {
+			for b in &codes {
+				assert_eq!(a.cmp(b), AsRef::<str>::as_ref(a).cmp(b.as_ref()), "{a} {b}");
+			}
+		}
+		codes.sort_unstable();
+		assert!(codes.windows(2).all(|pair| pair[0].to_string() < pair[1].to_string()));
+	}
+
+	#[test]
+	fn test_list() {
+		use crate::currency;
+		assert_eq!(currency::all(), currency::ARRAY);
+		assert!(currency::iter().eq(currency::ARRAY));
+		assert_eq!(currency::iter().next(), Some(currency::ADA));
+	}
+
+	#[test]
+	fn test_from_name() {
+		use crate::currency::{self, *};
+		assert_eq!(currency::from_name("Euro"), Some(EUR));
+		assert_eq!(currency::from_name(" united states  DOLLAR\n"), Some(USD));
+		assert_eq!(currency::from_name("Icelandic KRÓNA"), Some(ISK));
+		assert_eq!(currency::from_name("Belarusian Ruble"), Some(BYN));
+		assert_eq!(currency::from_name("Dollar"), None);
+		assert_eq!(currency::from_name(""), None);
+	}
+
 	#[test]
 	fn test_serde() {
 		let value = crate::currency::USD;
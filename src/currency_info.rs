@@ -0,0 +1,346 @@
+//! [`CurrencyInfo`] metadata of the known currencies.
+
+use crate::{currency::*, CurrencyCode};
+
+/// Metadata of a [known currency](crate::currency::ARRAY), see [`CurrencyCode::info`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyInfo {
+	/// The English name of the currency.
+	pub name: &'static str,
+	/// The display symbol of the currency, if it has a well-known one.
+	pub symbol: Option<&'static str>,
+	/// The count of digits after the decimal point in the currency's minor unit.
+	///
+	/// This is the [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) minor unit for fiat currencies, 2 for the codes it
+	/// has none for (precious metals and XDR), and the token decimals for cryptocurrencies.
+	pub decimal_digits: u8,
+}
+
+impl CurrencyInfo {
+	const fn new(name: &'static str, symbol: Option<&'static str>, decimal_digits: u8) -> Self {
+		Self { name, symbol, decimal_digits }
+	}
+}
+
+impl CurrencyCode {
+	/// The [`decimal_places`](CurrencyCode::decimal_places) of currencies that aren't [known](crate::currency::ARRAY),
+	/// as most currencies have cents.
+	pub const DEFAULT_DECIMAL_PLACES: u8 = 2;
+
+	/// Gets the metadata of the currency, if it is [known](crate::currency::ARRAY).
+	pub fn info(&self) -> Option<&'static CurrencyInfo> {
+		INFO.iter().find(|(currency, _)| currency == self).map(|(_, info)| info)
+	}
+
+	/// Gets the count of digits after the decimal point in the currency's minor unit (e.g. 2 for [`USD`], 0 for [`JPY`]
+	/// and 3 for [`BHD`]), see [`CurrencyInfo::decimal_digits`].
+	///
+	/// Unknown currencies fall back to [`CurrencyCode::DEFAULT_DECIMAL_PLACES`].
+	pub fn decimal_places(&self) -> u8 {
+		self.info().map_or(Self::DEFAULT_DECIMAL_PLACES, |info| info.decimal_digits)
+	}
+
+	/// Gets the display symbol of the currency (e.g. "$" for [`USD`]), if it is [known](crate::currency::ARRAY) and has
+	/// a well-known one, see [`CurrencyInfo::symbol`].
+	pub fn symbol(&self) -> Option<&'static str> {
+		self.info()?.symbol
+	}
+
+	/// Gets the [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) numeric code of the currency, if it has one (e.g.
+	/// 840 for [`USD`]).
+	///
+	/// Cryptocurrencies and unknown currencies have none.
+	pub fn numeric(&self) -> Option<u16> {
+		NUMERIC.iter().find(|(currency, _)| currency == self).map(|&(_, numeric)| numeric)
+	}
+
+	/// Gets the known currency of the given [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) numeric code, see
+	/// [`CurrencyCode::numeric`].
+	pub fn from_numeric(numeric: u16) -> Option<Self> {
+		NUMERIC.iter().find(|&&(_, n)| n == numeric).map(|&(currency, _)| currency)
+	}
+
+	/// Gets whether the currency is a known cryptocurrency.
+	pub fn is_crypto(&self) -> bool { CRYPTO.contains(self) }
+
+	/// Gets whether the currency is [known](crate::currency::ARRAY) and not a [cryptocurrency](CurrencyCode::is_crypto).
+	///
+	/// Note that this includes the precious metals (e.g. [`XAU`]) and [`XDR`].
+	pub fn is_fiat(&self) -> bool { !self.is_crypto() && self.info().is_some() }
+}
+
+/// The [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) numeric codes of the known currencies that have one.
+const NUMERIC: [(CurrencyCode, u16); 166] = [
+	(AED, 784), (AFN, 971), (ALL, 8), (AMD, 51), (ANG, 532), (AOA, 973), (ARS, 32), (AUD, 36),
+	(AWG, 533), (AZN, 944), (BAM, 977), (BBD, 52), (BDT, 50), (BGN, 975), (BHD, 48), (BIF, 108),
+	(BMD, 60), (BND, 96), (BOB, 68), (BRL, 986), (BSD, 44), (BTN, 64), (BWP, 72), (BYN, 933),
+	(BYR, 974), (BZD, 84), (CAD, 124), (CDF, 976), (CHF, 756), (CLF, 990), (CLP, 152), (CNY, 156),
+	(COP, 170), (CRC, 188), (CUC, 931), (CUP, 192), (CVE, 132), (CZK, 203), (DJF, 262), (DKK, 208),
+	(DOP, 214), (DZD, 12), (EGP, 818), (ERN, 232), (ETB, 230), (EUR, 978), (FJD, 242), (FKP, 238),
+	(GBP, 826), (GEL, 981), (GHS, 936), (GIP, 292), (GMD, 270), (GNF, 324), (GTQ, 320), (GYD, 328),
+	(HKD, 344), (HNL, 340), (HRK, 191), (HTG, 332), (HUF, 348), (IDR, 360), (ILS, 376), (INR, 356),
+	(IQD, 368), (IRR, 364), (ISK, 352), (JMD, 388), (JOD, 400), (JPY, 392), (KES, 404), (KGS, 417),
+	(KHR, 116), (KMF, 174), (KPW, 408), (KRW, 410), (KWD, 414), (KYD, 136), (KZT, 398), (LAK, 418),
+	(LBP, 422), (LKR, 144), (LRD, 430), (LSL, 426), (LTL, 440), (LVL, 428), (LYD, 434), (MAD, 504),
+	(MDL, 498), (MGA, 969), (MKD, 807), (MMK, 104), (MNT, 496), (MOP, 446), (MRO, 478), (MUR, 480),
+	(MVR, 462), (MWK, 454), (MXN, 484), (MYR, 458), (MZN, 943), (NAD, 516), (NGN, 566), (NIO, 558),
+	(NOK, 578), (NPR, 524), (NZD, 554), (OMR, 512), (PAB, 590), (PEN, 604), (PGK, 598), (PHP, 608),
+	(PKR, 586), (PLN, 985), (PYG, 600), (QAR, 634), (RON, 946), (RSD, 941), (RUB, 643), (RWF, 646),
+	(SAR, 682), (SBD, 90), (SCR, 690), (SDG, 938), (SEK, 752), (SGD, 702), (SHP, 654), (SLL, 694),
+	(SOS, 706), (SRD, 968), (STD, 678), (SVC, 222), (SYP, 760), (SZL, 748), (THB, 764), (TJS, 972),
+	(TMT, 934), (TND, 788), (TOP, 776), (TRY, 949), (TTD, 780), (TWD, 901), (TZS, 834), (UAH, 980),
+	(UGX, 800), (USD, 840), (UYU, 858), (UZS, 860), (VEF, 937), (VND, 704), (VUV, 548), (WST, 882),
+	(XAF, 950), (XAG, 961), (XAU, 959), (XCD, 951), (XDR, 960), (XOF, 952), (XPD, 964), (XPF, 953),
+	(XPT, 962), (YER, 886), (ZAR, 710), (ZMK, 894), (ZMW, 967), (ZWL, 932),
+];
+
+/// The known cryptocurrencies.
+const CRYPTO: [CurrencyCode; 16] = [ADA, ARB, AVAX, BNB, BTC, BUSD, DAI, DOT, ETH, LTC, MATIC, OP, SOL, USDC, USDT, XRP];
+
+/// The metadata of the known currencies, in the order of [`ARRAY`].
+pub(crate) static INFO: [(CurrencyCode, CurrencyInfo); ARRAY.len()] = [
+	(ADA, CurrencyInfo::new("Cardano", Some("₳"), 6)),
+	(AED, CurrencyInfo::new("United Arab Emirates Dirham", Some("د.إ"), 2)),
+	(AFN, CurrencyInfo::new("Afghan Afghani", Some("؋"), 2)),
+	(ALL, CurrencyInfo::new("Albanian Lek", Some("L"), 2)),
+	(AMD, CurrencyInfo::new("Armenian Dram", Some("֏"), 2)),
+	(ANG, CurrencyInfo::new("Netherlands Antillean Guilder", Some("ƒ"), 2)),
+	(AOA, CurrencyInfo::new("Angolan Kwanza", Some("Kz"), 2)),
+	(ARB, CurrencyInfo::new("Arbitrum", None, 18)),
+	(ARS, CurrencyInfo::new("Argentine Peso", Some("$"), 2)),
+	(AUD, CurrencyInfo::new("Australian Dollar", Some("A$"), 2)),
+	(AVAX, CurrencyInfo::new("Avalanche", None, 18)),
+	(AWG, CurrencyInfo::new("Aruban Florin", Some("ƒ"), 2)),
+	(AZN, CurrencyInfo::new("Azerbaijani Manat", Some("₼"), 2)),
+	(BAM, CurrencyInfo::new("Bosnia-Herzegovina Convertible Mark", Some("KM"), 2)),
+	(BBD, CurrencyInfo::new("Barbadian Dollar", Some("Bds$"), 2)),
+	(BDT, CurrencyInfo::new("Bangladeshi Taka", Some("৳"), 2)),
+	(BGN, CurrencyInfo::new("Bulgarian Lev", Some("лв"), 2)),
+	(BHD, CurrencyInfo::new("Bahraini Dinar", Some(".د.ب"), 3)),
+	(BIF, CurrencyInfo::new("Burundian Franc", Some("FBu"), 0)),
+	(BMD, CurrencyInfo::new("Bermudan Dollar", Some("BD$"), 2)),
+	(BNB, CurrencyInfo::new("Binance Coin", None, 18)),
+	(BND, CurrencyInfo::new("Brunei Dollar", Some("B$"), 2)),
+	(BOB, CurrencyInfo::new("Bolivian Boliviano", Some("Bs."), 2)),
+	(BRL, CurrencyInfo::new("Brazilian Real", Some("R$"), 2)),
+	(BSD, CurrencyInfo::new("Bahamian Dollar", Some("B$"), 2)),
+	(BTC, CurrencyInfo::new("Bitcoin", Some("₿"), 8)),
+	(BTN, CurrencyInfo::new("Bhutanese Ngultrum", Some("Nu."), 2)),
+	(BUSD, CurrencyInfo::new("Binance USD", None, 18)),
+	(BWP, CurrencyInfo::new("Botswanan Pula", Some("P"), 2)),
+	(BYN, CurrencyInfo::new("Belarusian Ruble", Some("Br"), 2)),
+	(BYR, CurrencyInfo::new("Belarusian Ruble (2000–2016)", Some("Br"), 0)),
+	(BZD, CurrencyInfo::new("Belize Dollar", Some("BZ$"), 2)),
+	(CAD, CurrencyInfo::new("Canadian Dollar", Some("CA$"), 2)),
+	(CDF, CurrencyInfo::new("Congolese Franc", Some("FC"), 2)),
+	(CHF, CurrencyInfo::new("Swiss Franc", Some("Fr."), 2)),
+	(CLF, CurrencyInfo::new("Chilean Unit of Account (UF)", Some("UF"), 4)),
+	(CLP, CurrencyInfo::new("Chilean Peso", Some("CLP$"), 0)),
+	(CNY, CurrencyInfo::new("Chinese Yuan", Some("¥"), 2)),
+	(COP, CurrencyInfo::new("Colombian Peso", Some("COL$"), 2)),
+	(CRC, CurrencyInfo::new("Costa Rican Colón", Some("₡"), 2)),
+	(CUC, CurrencyInfo::new("Cuban Convertible Peso", Some("CUC$"), 2)),
+	(CUP, CurrencyInfo::new("Cuban Peso", Some("₱"), 2)),
+	(CVE, CurrencyInfo::new("Cape Verdean Escudo", Some("Esc"), 2)),
+	(CZK, CurrencyInfo::new("Czech Koruna", Some("Kč"), 2)),
+	(DAI, CurrencyInfo::new("Dai", None, 18)),
+	(DJF, CurrencyInfo::new("Djiboutian Franc", Some("Fdj"), 0)),
+	(DKK, CurrencyInfo::new("Danish Krone", Some("kr."), 2)),
+	(DOP, CurrencyInfo::new("Dominican Peso", Some("RD$"), 2)),
+	(DOT, CurrencyInfo::new("Polkadot", None, 10)),
+	(DZD, CurrencyInfo::new("Algerian Dinar", Some("د.ج"), 2)),
+	(EGP, CurrencyInfo::new("Egyptian Pound", Some("E£"), 2)),
+	(ERN, CurrencyInfo::new("Eritrean Nakfa", Some("Nfk"), 2)),
+	(ETB, CurrencyInfo::new("Ethiopian Birr", Some("Br"), 2)),
+	(ETH, CurrencyInfo::new("Ethereum", Some("Ξ"), 18)),
+	(EUR, CurrencyInfo::new("Euro", Some("€"), 2)),
+	(FJD, CurrencyInfo::new("Fijian Dollar", Some("FJ$"), 2)),
+	(FKP, CurrencyInfo::new("Falkland Islands Pound", Some("£"), 2)),
+	(GBP, CurrencyInfo::new("British Pound Sterling", Some("£"), 2)),
+	(GEL, CurrencyInfo::new("Georgian Lari", Some("₾"), 2)),
+	(GGP, CurrencyInfo::new("Guernsey Pound", Some("£"), 2)),
+	(GHS, CurrencyInfo::new("Ghanaian Cedi", Some("GH₵"), 2)),
+	(GIP, CurrencyInfo::new("Gibraltar Pound", Some("£"), 2)),
+	(GMD, CurrencyInfo::new("Gambian Dalasi", Some("D"), 2)),
+	(GNF, CurrencyInfo::new("Guinean Franc", Some("FG"), 0)),
+	(GTQ, CurrencyInfo::new("Guatemalan Quetzal", Some("Q"), 2)),
+	(GYD, CurrencyInfo::new("Guyanaese Dollar", Some("GY$"), 2)),
+	(HKD, CurrencyInfo::new("Hong Kong Dollar", Some("HK$"), 2)),
+	(HNL, CurrencyInfo::new("Honduran Lempira", Some("L"), 2)),
+	(HRK, CurrencyInfo::new("Croatian Kuna", Some("kn"), 2)),
+	(HTG, CurrencyInfo::new("Haitian Gourde", Some("G"), 2)),
+	(HUF, CurrencyInfo::new("Hungarian Forint", Some("Ft"), 2)),
+	(IDR, CurrencyInfo::new("Indonesian Rupiah", Some("Rp"), 2)),
+	(ILS, CurrencyInfo::new("Israeli New Shekel", Some("₪"), 2)),
+	(IMP, CurrencyInfo::new("Manx Pound", Some("£"), 2)),
+	(INR, CurrencyInfo::new("Indian Rupee", Some("₹"), 2)),
+	(IQD, CurrencyInfo::new("Iraqi Dinar", Some("ع.د"), 3)),
+	(IRR, CurrencyInfo::new("Iranian Rial", Some("﷼"), 2)),
+	(ISK, CurrencyInfo::new("Icelandic Króna", Some("kr"), 0)),
+	(JEP, CurrencyInfo::new("Jersey Pound", Some("£"), 2)),
+	(JMD, CurrencyInfo::new("Jamaican Dollar", Some("J$"), 2)),
+	(JOD, CurrencyInfo::new("Jordanian Dinar", Some("JD"), 3)),
+	(JPY, CurrencyInfo::new("Japanese Yen", Some("¥"), 0)),
+	(KES, CurrencyInfo::new("Kenyan Shilling", Some("KSh"), 2)),
+	(KGS, CurrencyInfo::new("Kyrgystani Som", Some("с"), 2)),
+	(KHR, CurrencyInfo::new("Cambodian Riel", Some("៛"), 2)),
+	(KMF, CurrencyInfo::new("Comorian Franc", Some("CF"), 0)),
+	(KPW, CurrencyInfo::new("North Korean Won", Some("₩"), 2)),
+	(KRW, CurrencyInfo::new("South Korean Won", Some("₩"), 0)),
+	(KWD, CurrencyInfo::new("Kuwaiti Dinar", Some("KD"), 3)),
+	(KYD, CurrencyInfo::new("Cayman Islands Dollar", Some("CI$"), 2)),
+	(KZT, CurrencyInfo::new("Kazakhstani Tenge", Some("₸"), 2)),
+	(LAK, CurrencyInfo::new("Laotian Kip", Some("₭"), 2)),
+	(LBP, CurrencyInfo::new("Lebanese Pound", Some("L£"), 2)),
+	(LKR, CurrencyInfo::new("Sri Lankan Rupee", Some("Rs"), 2)),
+	(LRD, CurrencyInfo::new("Liberian Dollar", Some("L$"), 2)),
+	(LSL, CurrencyInfo::new("Lesotho Loti", Some("L"), 2)),
+	(LTC, CurrencyInfo::new("Litecoin", Some("Ł"), 8)),
+	(LTL, CurrencyInfo::new("Lithuanian Litas", Some("Lt"), 2)),
+	(LVL, CurrencyInfo::new("Latvian Lats", Some("Ls"), 2)),
+	(LYD, CurrencyInfo::new("Libyan Dinar", Some("LD"), 3)),
+	(MAD, CurrencyInfo::new("Moroccan Dirham", Some("DH"), 2)),
+	(MATIC, CurrencyInfo::new("Polygon", None, 18)),
+	(MDL, CurrencyInfo::new("Moldovan Leu", Some("L"), 2)),
+	(MGA, CurrencyInfo::new("Malagasy Ariary", Some("Ar"), 2)),
+	(MKD, CurrencyInfo::new("Macedonian Denar", Some("ден"), 2)),
+	(MMK, CurrencyInfo::new("Myanma Kyat", Some("K"), 2)),
+	(MNT, CurrencyInfo::new("Mongolian Tugrik", Some("₮"), 2)),
+	(MOP, CurrencyInfo::new("Macanese Pataca", Some("MOP$"), 2)),
+	(MRO, CurrencyInfo::new("Mauritanian Ouguiya (1973–2017)", Some("UM"), 2)),
+	(MUR, CurrencyInfo::new("Mauritian Rupee", Some("₨"), 2)),
+	(MVR, CurrencyInfo::new("Maldivian Rufiyaa", Some("Rf"), 2)),
+	(MWK, CurrencyInfo::new("Malawian Kwacha", Some("MK"), 2)),
+	(MXN, CurrencyInfo::new("Mexican Peso", Some("MX$"), 2)),
+	(MYR, CurrencyInfo::new("Malaysian Ringgit", Some("RM"), 2)),
+	(MZN, CurrencyInfo::new("Mozambican Metical", Some("MT"), 2)),
+	(NAD, CurrencyInfo::new("Namibian Dollar", Some("N$"), 2)),
+	(NGN, CurrencyInfo::new("Nigerian Naira", Some("₦"), 2)),
+	(NIO, CurrencyInfo::new("Nicaraguan Córdoba", Some("C$"), 2)),
+	(NOK, CurrencyInfo::new("Norwegian Krone", Some("kr"), 2)),
+	(NPR, CurrencyInfo::new("Nepalese Rupee", Some("रू"), 2)),
+	(NZD, CurrencyInfo::new("New Zealand Dollar", Some("NZ$"), 2)),
+	(OMR, CurrencyInfo::new("Omani Rial", Some("ر.ع."), 3)),
+	(OP, CurrencyInfo::new("Optimism", None, 18)),
+	(PAB, CurrencyInfo::new("Panamanian Balboa", Some("B/."), 2)),
+	(PEN, CurrencyInfo::new("Peruvian Sol", Some("S/"), 2)),
+	(PGK, CurrencyInfo::new("Papua New Guinean Kina", Some("K"), 2)),
+	(PHP, CurrencyInfo::new("Philippine Peso", Some("₱"), 2)),
+	(PKR, CurrencyInfo::new("Pakistani Rupee", Some("₨"), 2)),
+	(PLN, CurrencyInfo::new("Polish Zloty", Some("zł"), 2)),
+	(PYG, CurrencyInfo::new("Paraguayan Guarani", Some("₲"), 0)),
+	(QAR, CurrencyInfo::new("Qatari Riyal", Some("ر.ق"), 2)),
+	(RON, CurrencyInfo::new("Romanian Leu", Some("lei"), 2)),
+	(RSD, CurrencyInfo::new("Serbian Dinar", Some("дин."), 2)),
+	(RUB, CurrencyInfo::new("Russian Ruble", Some("₽"), 2)),
+	(RWF, CurrencyInfo::new("Rwandan Franc", Some("FRw"), 0)),
+	(SAR, CurrencyInfo::new("Saudi Riyal", Some("ر.س"), 2)),
+	(SBD, CurrencyInfo::new("Solomon Islands Dollar", Some("SI$"), 2)),
+	(SCR, CurrencyInfo::new("Seychellois Rupee", Some("₨"), 2)),
+	(SDG, CurrencyInfo::new("Sudanese Pound", Some("ج.س."), 2)),
+	(SEK, CurrencyInfo::new("Swedish Krona", Some("kr"), 2)),
+	(SGD, CurrencyInfo::new("Singapore Dollar", Some("S$"), 2)),
+	(SHP, CurrencyInfo::new("Saint Helena Pound", Some("£"), 2)),
+	(SLL, CurrencyInfo::new("Sierra Leonean Leone (1964–2022)", Some("Le"), 2)),
+	(SOL, CurrencyInfo::new("Solana", None, 9)),
+	(SOS, CurrencyInfo::new("Somali Shilling", Some("Sh"), 2)),
+	(SRD, CurrencyInfo::new("Surinamese Dollar", Some("SRD$"), 2)),
+	(STD, CurrencyInfo::new("São Tomé and Príncipe Dobra (1977–2017)", Some("Db"), 2)),
+	(SVC, CurrencyInfo::new("Salvadoran Colón", Some("₡"), 2)),
+	(SYP, CurrencyInfo::new("Syrian Pound", Some("£S"), 2)),
+	(SZL, CurrencyInfo::new("Swazi Lilangeni", Some("E"), 2)),
+	(THB, CurrencyInfo::new("Thai Baht", Some("฿"), 2)),
+	(TJS, CurrencyInfo::new("Tajikistani Somoni", Some("SM"), 2)),
+	(TMT, CurrencyInfo::new("Turkmenistani Manat", Some("m"), 2)),
+	(TND, CurrencyInfo::new("Tunisian Dinar", Some("DT"), 3)),
+	(TOP, CurrencyInfo::new("Tongan Paʻanga", Some("T$"), 2)),
+	(TRY, CurrencyInfo::new("Turkish Lira", Some("₺"), 2)),
+	(TTD, CurrencyInfo::new("Trinidad and Tobago Dollar", Some("TT$"), 2)),
+	(TWD, CurrencyInfo::new("New Taiwan Dollar", Some("NT$"), 2)),
+	(TZS, CurrencyInfo::new("Tanzanian Shilling", Some("TSh"), 2)),
+	(UAH, CurrencyInfo::new("Ukrainian Hryvnia", Some("₴"), 2)),
+	(UGX, CurrencyInfo::new("Ugandan Shilling", Some("USh"), 0)),
+	(USD, CurrencyInfo::new("United States Dollar", Some("$"), 2)),
+	(USDC, CurrencyInfo::new("USD Coin", None, 6)),
+	(USDT, CurrencyInfo::new("Tether", None, 6)),
+	(UYU, CurrencyInfo::new("Uruguayan Peso", Some("$U"), 2)),
+	(UZS, CurrencyInfo::new("Uzbekistan Som", Some("soʻm"), 2)),
+	(VEF, CurrencyInfo::new("Venezuelan Bolívar Fuerte", Some("Bs.F"), 2)),
+	(VND, CurrencyInfo::new("Vietnamese Dong", Some("₫"), 0)),
+	(VUV, CurrencyInfo::new("Vanuatu Vatu", Some("VT"), 0)),
+	(WST, CurrencyInfo::new("Samoan Tala", Some("WS$"), 2)),
+	(XAF, CurrencyInfo::new("Central African CFA Franc", Some("FCFA"), 0)),
+	(XAG, CurrencyInfo::new("Silver (troy ounce)", None, 2)),
+	(XAU, CurrencyInfo::new("Gold (troy ounce)", None, 2)),
+	(XCD, CurrencyInfo::new("East Caribbean Dollar", Some("EC$"), 2)),
+	(XDR, CurrencyInfo::new("Special Drawing Rights", None, 2)),
+	(XOF, CurrencyInfo::new("West African CFA Franc", Some("CFA"), 0)),
+	(XPD, CurrencyInfo::new("Palladium (troy ounce)", None, 2)),
+	(XPF, CurrencyInfo::new("CFP Franc", Some("₣"), 0)),
+	(XPT, CurrencyInfo::new("Platinum (troy ounce)", None, 2)),
+	(XRP, CurrencyInfo::new("XRP", None, 6)),
+	(YER, CurrencyInfo::new("Yemeni Rial", Some("﷼"), 2)),
+	(ZAR, CurrencyInfo::new("South African Rand", Some("R"), 2)),
+	(ZMK, CurrencyInfo::new("Zambian Kwacha (1968–2012)", Some("ZK"), 2)),
+	(ZMW, CurrencyInfo::new("Zambian Kwacha", Some("ZK"), 2)),
+	(ZWL, CurrencyInfo::new("Zimbabwean Dollar", Some("Z$"), 2)),
+];
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_info() {
+		assert!(INFO.iter().map(|&(currency, _)| currency).eq(ARRAY));
+		assert_eq!(EUR.info(), Some(&CurrencyInfo::new("Euro", Some("€"), 2)));
+		assert_eq!(JPY.info().unwrap().decimal_digits, 0);
+		assert_eq!(BHD.info().unwrap().decimal_digits, 3);
+		assert_eq!(XAU.info().unwrap().symbol, None);
+		assert_eq!("ABC".parse::<CurrencyCode>().unwrap().info(), None);
+	}
+
+	#[test]
+	fn test_decimal_places() {
+		assert_eq!(USD.decimal_places(), 2);
+		assert_eq!(EUR.decimal_places(), 2);
+		assert_eq!(JPY.decimal_places(), 0);
+		assert_eq!(BHD.decimal_places(), 3);
+		assert_eq!(KWD.decimal_places(), 3);
+		assert_eq!("ABC".parse::<CurrencyCode>().unwrap().decimal_places(), CurrencyCode::DEFAULT_DECIMAL_PLACES);
+	}
+
+	#[test]
+	fn test_symbol() {
+		assert_eq!(USD.symbol(), Some("$"));
+		assert_eq!(EUR.symbol(), Some("€"));
+		assert_eq!(JPY.symbol(), Some("¥"));
+		assert_eq!(BTC.symbol(), Some("₿"));
+		assert_eq!(XAU.symbol(), None);
+		assert_eq!("ABC".parse::<CurrencyCode>().unwrap().symbol(), None);
+	}
+
+	#[test]
+	fn test_crypto() {
+		assert!(BTC.is_crypto() && !BTC.is_fiat());
+		assert!(USDT.is_crypto() && !USDT.is_fiat());
+		assert!(!EUR.is_crypto() && EUR.is_fiat());
+		let unknown = "ABC".parse::<CurrencyCode>().unwrap();
+		assert!(!unknown.is_crypto() && !unknown.is_fiat());
+		assert_eq!(ARRAY.iter().filter(|currency| currency.is_crypto()).count(), CRYPTO.len());
+	}
+
+	#[test]
+	fn test_numeric() {
+		assert_eq!(USD.numeric(), Some(840));
+		assert_eq!(EUR.numeric(), Some(978));
+		assert_eq!(JPY.numeric(), Some(392));
+		assert_eq!(ALL.numeric(), Some(8));
+		assert_eq!(BTC.numeric(), None);
+		assert_eq!(CurrencyCode::from_numeric(840), Some(USD));
+		assert_eq!(CurrencyCode::from_numeric(392), Some(JPY));
+		assert_eq!(CurrencyCode::from_numeric(999), None);
+		assert!(NUMERIC.iter().all(|&(currency, numeric)| CurrencyCode::from_numeric(numeric) == Some(currency)));
+		assert!(!NUMERIC.iter().any(|(currency, _)| currency.is_crypto()));
+	}
+}
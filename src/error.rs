@@ -12,6 +12,29 @@ pub enum Error {
 	/// Failed to parse the response.
 	#[error("failed to parse the response")]
 	ResponseParseError,
+	/// Failed to parse a rate in the response.
+	#[error("failed to parse the {currency} rate from the response: {error}")]
+	RateParseError {
+		/// The currency whose rate is invalid.
+		currency: crate::CurrencyCode,
+		/// The parse error, with the [`FromScientific`](crate::scientific::FromScientific) error formatted.
+		#[source]
+		error: crate::scientific::ScientificBytesError<String>,
+	},
+	/// The response has more currencies than fit in the [`Rates`](crate::Rates), which stored those that do.
+	#[error("received {received} rates but only {stored} fit")]
+	CapacityExceeded {
+		/// The count of rates in the response.
+		received: usize,
+		/// The count of rates that were stored.
+		stored: usize,
+	},
+	/// The API token isn't a valid header value.
+	#[error("the API token isn't a valid header value")]
+	InvalidToken,
+	/// The request URL doesn't fit in its buffer, e.g. too many currencies were requested at once.
+	#[error("the request URL is too long")]
+	UrlTooLong,
 	/// Failed to parse the rate-limit headers.
 	#[error("failed to parse the rate-limits headers from the response")]
 	RateLimitParseError,
@@ -1,28 +1,39 @@
 //! API for the [`latest`](https://currencyapi.com/docs/latest) endpoint.
 
-use std::{collections::HashMap, str::FromStr, io};
+use std::{borrow::Cow, collections::HashMap, fmt, str::FromStr, io, time::SystemTime};
 
+use reqwest::{header::{HeaderMap, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED}, StatusCode};
 use serde::Deserialize;
 use serde_json::value::RawValue;
 
-use crate::{CurrencyCode, scientific::FromScientific, rates::Rates, Error, rate_limit::RateLimitData, url::{UrlPart, NoBaseCurrency, self}, RateLimitIgnore};
+use crate::{CurrencyCode, CurrencyError, scientific::FromScientific, rates::Rates, RatesVec, Error, rate_limit::RateLimitData, url::{UrlPart, NoBaseCurrency, self}, RateLimitIgnore, Token};
 
 /// Request to the [`latest`](https://currencyapi.com/docs/latest) endpoint.
 #[derive(Debug)]
-pub struct Request(pub(crate) reqwest::Request);
+pub struct Request {
+	/// The HTTP request.
+	pub(crate) request: reqwest::Request,
+	/// The requested currencies, kept to report the ones [missing](Metadata::missing) from the response.
+	pub(crate) currencies: Vec<CurrencyCode>,
+}
 
 impl Clone for Request {
 	#[inline] fn clone(&self) -> Self {
-		// try_clone should always succeed since there should never be a body stream.
-		Self(self.0.try_clone().unwrap())
+		Self {
+			// try_clone should always succeed since there should never be a body stream.
+			request: self.request.try_clone().unwrap(),
+			currencies: self.currencies.clone(),
+		}
 	}
 }
 
 /// [`Request`] builder.
-#[derive(Debug, Hash, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+///
+/// It isn't [`Copy`] since it may own its [`Token`], [clone](Clone) it instead.
+#[derive(Debug, Hash, Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub struct Builder<'a, Currencies = AllCurrencies, BaseCurrency = NoBaseCurrency> {
 	/// The [API token](https://currencyapi.com/docs/#authentication-api-key-information).
-	pub token: &'a str,
+	pub token: Token<'a>,
 	/// The [`base_currency`](https://currencyapi.com/docs/latest#:~:text=Your%20API%20Key-,base_currency,-string).
 	pub base_currency: BaseCurrency,
 	/// The [`currencies`](https://currencyapi.com/docs/latest#:~:text=based%20on%20USD-,currencies,-string).
@@ -36,6 +47,10 @@ impl<'a> From<&'a str> for Builder<'a, AllCurrencies, NoBaseCurrency> {
 	#[inline] fn from(token: &'a str) -> Self { Self::new(token) }
 }
 
+impl<'a> From<Token<'a>> for Builder<'a, AllCurrencies, NoBaseCurrency> {
+	#[inline] fn from(token: Token<'a>) -> Self { Self::new(token) }
+}
+
 impl<'a, Currencies, BaseCurrency> Builder<'a, Currencies, BaseCurrency> {
 	/// Sets the [`currencies`](Builder::currencies).
 	#[inline] pub fn currencies<CurrenciesNew>(self, currencies: CurrenciesNew) -> Builder<'a, CurrenciesNew, BaseCurrency> {
@@ -46,6 +61,17 @@ impl<'a, Currencies, BaseCurrency> Builder<'a, Currencies, BaseCurrency> {
 		}
 	}
 
+	/// Sets the [`currencies`](Builder::currencies) from a comma-separated list, e.g. `"USD,EUR,GBP"`.
+	///
+	/// Whitespace around each currency is ignored, and an empty list requests all currencies.
+	/// Fails on the first invalid currency.
+	pub fn currencies_from_str(self, currencies: &str) -> Result<Builder<'a, Vec<CurrencyCode>, BaseCurrency>, CurrencyError> {
+		let currencies = if currencies.trim().is_empty() { Vec::new() } else {
+			currencies.split(',').map(|currency| currency.trim().parse()).collect::<Result<_, _>>()?
+		};
+		Ok(self.currencies(currencies))
+	}
+
 	/// Sets the [`base_currency`](Builder::base_currency).
 	#[inline] pub fn base_currency<BaseCurrencyNew>(self, base_currency: BaseCurrencyNew) -> Builder<'a, Currencies, crate::url::BaseCurrency<BaseCurrencyNew>> where crate::url::BaseCurrency<BaseCurrencyNew>: UrlPart {
 		Builder {
@@ -67,9 +93,9 @@ impl<'a, Currencies, BaseCurrency> Builder<'a, Currencies, BaseCurrency> {
 
 impl<'a> Builder<'a, AllCurrencies, NoBaseCurrency> {
 	/// Creates a new [`Builder`] with the given [API token](Builder::token).
-	#[inline] pub const fn new(token: &'a str) -> Self {
+	#[inline] pub fn new(token: impl Into<Token<'a>>) -> Self {
 		Builder {
-			token,
+			token: token.into(),
 			base_currency: NoBaseCurrency,
 			currencies: std::iter::empty(),
 		}
@@ -78,80 +104,250 @@ impl<'a> Builder<'a, AllCurrencies, NoBaseCurrency> {
 
 impl<'a, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency: UrlPart> Builder<'a, Currencies, BaseCurrency> {
 	/// Builds the [`Request`].
-	#[inline] pub fn build(self) -> Request { self.into() }
+	///
+	/// Fails with [`Error::InvalidToken`] if the [token](Builder::token) isn't a valid header value, e.g. it has a
+	/// trailing newline, and with [`Error::UrlTooLong`] if the [`currencies`](Builder::currencies) don't fit in the
+	/// request URL, see [`Rates::fetch_latest_chunked`] to split them across requests.
+	#[inline] pub fn build(self) -> Result<Request, Error> { self.try_into() }
 }
 
-impl<'a, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency> Builder<'a, Currencies, BaseCurrency> where BaseCurrency: crate::url::UrlPart {
-	fn write_url(self, mut writer: impl io::Write) -> io::Result<()> {
-		url::base::LATEST.write_url_part(&mut writer, b"")?;
-		let sep = if self.base_currency.write_url_part(&mut writer, b"?")? { b"&" } else { b"?" };
-		url::Currencies(self.currencies).write_url_part(writer, sep)?;
-		Ok(())
+impl<'a, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency: UrlPart + Clone> Builder<'a, Currencies, BaseCurrency> {
+	/// Splits into builders whose [`currencies`](Builder::currencies) each fit in a request URL, dropping duplicate
+	/// currencies.
+	///
+	/// Yields a single builder when no currencies are specified.
+	pub(crate) fn chunked(self) -> Vec<Builder<'a, Vec<CurrencyCode>, BaseCurrency>> {
+		// The URL without the currencies, which take the rest of its capacity.
+		let mut prefix = Vec::new();
+		write_url(self.base_currency.clone(), std::iter::empty(), &mut prefix).expect("writing to a Vec can't fail");
+		// Plus one since the last currency has no separator.
+		let budget = url::capacity::URL_CAPACITY_LATEST.saturating_sub(prefix.len() + b"&currencies=".len()) + 1;
+		let mut seen = std::collections::HashSet::new();
+		let mut chunks = vec![Vec::new()];
+		let mut chunk_cost = 0;
+		for currency in self.currencies.into_iter().filter(|&currency| seen.insert(currency)) {
+			// The currency and its separator.
+			let cost = AsRef::<[u8]>::as_ref(&currency).len() + 1;
+			if chunk_cost > 0 && chunk_cost + cost > budget {
+				chunks.push(Vec::new());
+				chunk_cost = 0;
+			}
+			chunks.last_mut().unwrap().push(currency);
+			chunk_cost += cost;
+		}
+		chunks.into_iter().map(|currencies| Builder {
+			token: self.token.clone(),
+			base_currency: self.base_currency.clone(),
+			currencies,
+		}).collect()
 	}
 }
 
-impl<'a, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency: UrlPart> From<Builder<'a, Currencies, BaseCurrency>> for Request {
-	#[inline] fn from(builder: Builder<'a, Currencies, BaseCurrency>) -> Self {
+fn write_url(base_currency: impl UrlPart, currencies: impl IntoIterator<Item = CurrencyCode>, mut writer: impl io::Write) -> io::Result<()> {
+	url::base::LATEST.write_url_part(&mut writer, b"")?;
+	let sep = if base_currency.write_url_part(&mut writer, b"?")? { b"&" } else { b"?" };
+	url::Currencies(currencies).write_url_part(writer, sep)?;
+	Ok(())
+}
+
+impl<'a, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency: UrlPart> TryFrom<Builder<'a, Currencies, BaseCurrency>> for Request {
+	type Error = Error;
+
+	fn try_from(builder: Builder<'a, Currencies, BaseCurrency>) -> Result<Self, Self::Error> {
 		let mut url_buf = [0u8; crate::url::capacity::URL_CAPACITY_LATEST];
 		let mut writer = &mut url_buf[..];
-		let token = builder.token;
-		builder.write_url(&mut writer).expect("failed to construct /latest request URL");
+		let Builder { mut token, base_currency, currencies } = builder;
+		let currencies = currencies.into_iter().collect::<Vec<_>>();
+		write_url(base_currency, currencies.iter().copied(), &mut writer).map_err(|_| Error::UrlTooLong)?;
 
 		let url_len = writer.as_ptr() as usize - url_buf.as_ptr() as usize;
 		let url_buf = &url_buf[..url_len];
 		let url = unsafe {
 			// SAFETY: the buffer is built from valid UTF-8.
-			std::str::from_utf8_unchecked(&url_buf)
+			std::str::from_utf8_unchecked(url_buf)
 		};
 		let url = url.parse::<reqwest::Url>().unwrap();
 		let mut request = reqwest::Request::new(reqwest::Method::GET, url);
-		request.headers_mut().insert("apikey", token.parse().unwrap());
-		Self(request)
+		let mut apikey = match token.take() {
+			Cow::Owned(token) => HeaderValue::try_from(token),
+			Cow::Borrowed(token) => HeaderValue::from_str(token),
+		}.map_err(|_| Error::InvalidToken)?;
+		apikey.set_sensitive(true);
+		request.headers_mut().insert("apikey", apikey);
+		Ok(Self { request, currencies })
 	}
 }
 
 impl Request {
 	/// Sends the request.
-	#[inline] pub async fn send<const N: usize, DateTime: FromStr, RATE: FromScientific, RateLimit: for<'x> RateLimitData<'x>>(
+	///
+	/// Rates of currencies already in `rates` are replaced rather than duplicated. Fails with
+	/// [`Error::CapacityExceeded`] if the response has more new currencies than fit, after storing those that do.
+	#[inline] pub async fn send<const N: usize, DateTime: FromStr, RATE: FromScientific<Error: fmt::Display>, RateLimit: for<'x> RateLimitData<'x>>(
+		self,
+		rates: &mut Rates<RATE, N>,
+		client: &reqwest::Client,
+	) -> Result<Metadata<DateTime, RateLimit>, Error> {
+		let response = client.execute(self.request).await?;
+		receive(response, self.currencies, |iter| fill(rates, iter)).await
+	}
+
+	/// Sends the request into a [`RatesVec`], which never truncates the rates.
+	#[inline] pub async fn send_vec<DateTime: FromStr, RATE: FromScientific<Error: fmt::Display>, RateLimit: for<'x> RateLimitData<'x>>(
 		self,
+		rates: &mut RatesVec<RATE>,
+		client: &reqwest::Client,
+	) -> Result<Metadata<DateTime, RateLimit>, Error> {
+		let response = client.execute(self.request).await?;
+		receive(response, self.currencies, |iter| {
+			for (currency, rate) in iter { rates.insert_or_update(currency, rate); }
+			rates.sort();
+			Ok(())
+		}).await
+	}
+
+	/// Sends the request, conditional on the data having changed since the `previous` response.
+	///
+	/// Sends the [`etag`](Metadata::etag) and [`last_modified`](Metadata::last_modified) of the previous response, if
+	/// present. If the data wasn't modified, the rates are left untouched and the previous [`Metadata`] is returned with
+	/// its [`modified`](Metadata::modified) flag unset and the updated rate-limit.
+	pub async fn send_if_modified<const N: usize, DateTime: FromStr, RATE: FromScientific<Error: fmt::Display>, RateLimit: for<'x> RateLimitData<'x>>(
+		mut self,
 		rates: &mut Rates<RATE, N>,
 		client: &reqwest::Client,
+		previous: Metadata<DateTime, RateLimit>,
 	) -> Result<Metadata<DateTime, RateLimit>, Error> {
-		let response = client.execute(self.0).await?;
-		if response.status() == 429 { return Err(Error::RateLimitError); }
-		let response = response.error_for_status()?;
-
-		#[derive(Deserialize)]
-		struct Payload<'a> {
-			#[serde(borrow)]
-			meta: PayloadMeta<'a>,
-			#[serde(borrow)]
-			data: PayloadData<'a>,
+		insert_conditional_headers(self.request.headers_mut(), &previous);
+		let response = client.execute(self.request).await?;
+		if response.status() == StatusCode::NOT_MODIFIED {
+			let rate_limit = (&response)
+				.try_into()
+				.map_err(|_| Error::RateLimitParseError)?;
+			return Ok(not_modified(rates, previous, rate_limit));
+		}
+		receive(response, self.currencies, |iter| fill(rates, iter)).await
+	}
+}
+
+/// Inserts the conditional headers of [`Request::send_if_modified`] from the `previous` response, skipping those
+/// that aren't valid header values.
+fn insert_conditional_headers<DateTime, RateLimit>(headers: &mut HeaderMap, previous: &Metadata<DateTime, RateLimit>) {
+	for (name, value) in [(IF_NONE_MATCH, &previous.etag), (IF_MODIFIED_SINCE, &previous.last_modified)] {
+		if let Some(value) = value.as_deref().and_then(|value| HeaderValue::from_str(value).ok()) {
+			headers.insert(name, value);
 		}
+	}
+}
+
+/// Handles a not-modified response, marking the `rates` as up to date and returning the `previous` metadata with its
+/// [`modified`](Metadata::modified) flag unset and the updated `rate_limit`.
+fn not_modified<const N: usize, RATE, DateTime, RateLimit>(
+	rates: &mut Rates<RATE, N>,
+	previous: Metadata<DateTime, RateLimit>,
+	rate_limit: RateLimit,
+) -> Metadata<DateTime, RateLimit> {
+	rates.set_updated_at(Some(SystemTime::now()));
+	Metadata { rate_limit, modified: false, ..previous }
+}
 
-		#[derive(Deserialize)]
-		struct PayloadMeta<'a> { last_updated_at: &'a str }
-
-		#[derive(Deserialize)]
-		struct PayloadData<'a> (#[serde(borrow)] HashMap<&'a str, PayloadDataEntry<'a>>);
-
-		#[derive(Deserialize)]
-		struct PayloadDataEntry<'a> { #[serde(borrow)] value: &'a RawValue }
-
-		let rate_limit = (&response)
-			.try_into()
-			.map_err(|_| Error::RateLimitParseError)?;
-		let payload = response.bytes().await?;
-		let payload = serde_json::from_slice::<Payload>(&payload).unwrap();
-		let last_updated_at = payload.meta.last_updated_at.parse::<DateTime>().unwrap_or_else(|_| todo!());
-		rates.extend_capped(
-			payload.data.0.iter()
-				.map(|(&currency, entry)| (currency.parse().unwrap(), RATE::parse_scientific(entry.value.get()).unwrap_or_else(|_| todo!())))
-		);
-		Ok(Metadata {
-			last_updated_at,
-			rate_limit,
+/// Fills [`Rates`] from a response, replacing the existing rates of currencies, and pushing new ones until full.
+fn fill<const N: usize, RATE>(rates: &mut Rates<RATE, N>, iter: &mut dyn Iterator<Item = (CurrencyCode, RATE)>) -> Result<(), Error> {
+	let (mut received, mut stored) = (0, 0);
+	for (currency, rate) in iter {
+		received += 1;
+		if rates.insert_or_update(currency, rate).is_ok() { stored += 1; }
+	}
+	rates.sort();
+	rates.set_updated_at(Some(SystemTime::now()));
+	if stored < received { Err(Error::CapacityExceeded { received, stored }) } else { Ok(()) }
+}
+
+#[derive(Deserialize)]
+struct Payload<'a> {
+	#[serde(borrow)]
+	meta: PayloadMeta<'a>,
+	#[serde(borrow)]
+	data: PayloadData<'a>,
+}
+
+#[derive(Deserialize)]
+struct PayloadMeta<'a> { last_updated_at: &'a str }
+
+#[derive(Deserialize)]
+struct PayloadData<'a> (#[serde(borrow)] HashMap<&'a str, PayloadDataEntry<'a>>);
+
+#[derive(Deserialize)]
+struct PayloadDataEntry<'a> { #[serde(borrow)] value: &'a RawValue }
+
+/// Parses the rates of a [`Request`] response.
+fn parse_rates<RATE: FromScientific<Error: fmt::Display>>(data: &PayloadData) -> Result<Vec<(CurrencyCode, RATE)>, Error> {
+	data.0.iter()
+		.map(|(&currency, entry)| {
+			let currency = currency.parse::<CurrencyCode>().map_err(|_| Error::ResponseParseError)?;
+			let rate = RATE::parse_scientific_bytes(entry.value.get().as_bytes())
+				.map_err(|error| Error::RateParseError { currency, error: error.map_parse(|error| error.to_string()) })?;
+			Ok((currency, rate))
 		})
+		.collect()
+}
+
+/// Gets the requested `currencies` that are absent from a response's rates.
+fn missing(currencies: Vec<CurrencyCode>, data: &PayloadData) -> Vec<CurrencyCode> {
+	currencies.into_iter()
+		.filter(|currency| !data.0.contains_key(AsRef::<str>::as_ref(currency)))
+		.collect()
+}
+
+/// Reads a [`Request`] response, passing its rates to `fill`.
+async fn receive<DateTime: FromStr, RATE: FromScientific<Error: fmt::Display>, RateLimit: for<'x> RateLimitData<'x>>(
+	response: reqwest::Response,
+	currencies: Vec<CurrencyCode>,
+	fill: impl FnOnce(&mut dyn Iterator<Item = (CurrencyCode, RATE)>) -> Result<(), Error>,
+) -> Result<Metadata<DateTime, RateLimit>, Error> {
+	if response.status() == 429 { return Err(Error::RateLimitError); }
+	let response = response.error_for_status()?;
+
+	let rate_limit = (&response)
+		.try_into()
+		.map_err(|_| Error::RateLimitParseError)?;
+	let header = |name| response.headers().get(name).and_then(|value| value.to_str().ok()).map(str::to_owned);
+	let etag = header(ETAG);
+	let last_modified = header(LAST_MODIFIED);
+	let payload = response.bytes().await?;
+	let payload = serde_json::from_slice::<Payload>(&payload).map_err(|_| Error::ResponseParseError)?;
+	let last_updated_at = payload.meta.last_updated_at.parse::<DateTime>().map_err(|_| Error::ResponseParseError)?;
+	fill(&mut parse_rates(&payload.data)?.into_iter())?;
+	Ok(Metadata {
+		last_updated_at,
+		rate_limit,
+		missing: missing(currencies, &payload.data),
+		etag,
+		last_modified,
+		modified: true,
+	})
+}
+
+/// Fetches [`latest`](self) rates in a single call.
+///
+/// A thin layer over [`Builder`] and [`Request::send`] for the simple cases, use them directly for
+/// finer control.
+#[derive(Debug, Clone)]
+pub struct Latest<'a, Currencies = AllCurrencies>(Builder<'a, Currencies, url::BaseCurrency<Option<CurrencyCode>>>);
+
+impl<'a, Currencies: IntoIterator<Item = CurrencyCode>> Latest<'a, Currencies> {
+	/// Creates a new [`Latest`] request for the given [`currencies`](Builder::currencies) (empty for all), relative to
+	/// the [`base_currency`](Builder::base_currency) (USD if [`None`]).
+	#[inline] pub fn new(token: impl Into<Token<'a>>, base_currency: Option<CurrencyCode>, currencies: Currencies) -> Self {
+		Self(Builder::new(token).base_currency(base_currency).currencies(currencies))
+	}
+
+	/// Sends the request, returning the rates and the response [`Metadata`].
+	///
+	/// Fails with [`Error::CapacityExceeded`] if the response has more than `N` rates.
+	pub async fn send<const N: usize>(self, client: &reqwest::Client) -> Result<(Rates<f64, N>, Metadata<String>), Error> {
+		let mut rates = Rates::new();
+		let metadata = self.0.build()?.send(&mut rates, client).await?;
+		Ok((rates, metadata))
 	}
 }
 
@@ -162,4 +358,160 @@ pub struct Metadata<DateTime, RateLimit = RateLimitIgnore> {
 	pub last_updated_at: DateTime,
 	/// Rate-limit data.
 	pub rate_limit: RateLimit,
+	/// Requested [`currencies`](Builder::currencies) that are absent from the response.
+	pub missing: Vec<CurrencyCode>,
+	/// The [`ETag`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/ETag) response header.
+	pub etag: Option<String>,
+	/// The [`Last-Modified`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Last-Modified) response header.
+	pub last_modified: Option<String>,
+	/// Whether the data was modified, see [`Request::send_if_modified`].
+	pub modified: bool,
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::RateLimitPartial;
+
+	#[test]
+	fn test_currencies_from_str() {
+		use crate::currency::*;
+		let builder = Builder::new("").currencies_from_str(" USD,EUR , GBP").unwrap();
+		assert_eq!(builder.currencies, [USD, EUR, GBP]);
+		let builder = Builder::new("").currencies_from_str(" ").unwrap();
+		assert!(builder.currencies.is_empty());
+		match Builder::new("").currencies_from_str("USD,E,GBP") {
+			Err(CurrencyError::TooShort) => {},
+			_ => panic!(),
+		}
+	}
+
+	#[test]
+	fn test_token_redacted() {
+		let builder = Builder::new("secret");
+		assert!(!format!("{builder:?}").contains("secret"));
+		assert!(!format!("{:?}", builder.build().unwrap()).contains("secret"));
+	}
+
+	#[test]
+	fn test_invalid_token() {
+		assert!(matches!(Builder::new("secret\n").build(), Err(Error::InvalidToken)));
+		assert!(matches!(Builder::new(String::from("sec\x7fret")).build(), Err(Error::InvalidToken)));
+		assert!(Builder::new(String::from("secret")).build().is_ok());
+	}
+
+	#[test]
+	fn test_chunked() {
+		use crate::currency::*;
+		assert_eq!(Builder::new("").chunked().len(), 1);
+
+		let currencies = std::iter::repeat_n(ARRAY, 3).flatten().chain([MATIC, USD]);
+		let chunks = Builder::new("").currencies(currencies).chunked();
+		assert_eq!(chunks.len(), 1);
+		assert_eq!(chunks[0].currencies, ARRAY);
+
+		let custom = (b'A'..=b'Z')
+			.flat_map(|a| (b'A'..=b'Z').map(move |b| [a, b, b'X', b'Y', b'Z']))
+			.map(|code| std::str::from_utf8(&code).unwrap().parse::<CurrencyCode>().unwrap())
+			.collect::<Vec<_>>();
+		let chunks = Builder::new("").base_currency(MATIC).currencies(custom.iter().copied()).chunked();
+		assert!(chunks.len() > 1);
+		assert_eq!(chunks.iter().flat_map(|chunk| chunk.currencies.iter().copied()).collect::<Vec<_>>(), custom);
+		for chunk in chunks { chunk.build().unwrap(); }
+
+		let base = "X".repeat(64);
+		let chunks = Builder::new("").base_currency(base.as_str()).currencies(custom.iter().copied()).chunked();
+		assert_eq!(chunks.iter().flat_map(|chunk| chunk.currencies.iter().copied()).collect::<Vec<_>>(), custom);
+		for chunk in chunks { chunk.build().unwrap(); }
+	}
+
+	#[test]
+	fn test_url_too_long() {
+		use crate::currency::*;
+		assert!(Builder::new("").base_currency(MATIC).currencies(ARRAY).build().is_ok());
+		let currencies = std::iter::repeat_n(ARRAY, 2).flatten();
+		assert!(matches!(Builder::new("").base_currency(MATIC).currencies(currencies).build(), Err(Error::UrlTooLong)));
+		let base = "X".repeat(64);
+		assert!(matches!(Builder::new("").base_currency(base.as_str()).currencies(ARRAY).build(), Err(Error::UrlTooLong)));
+		let base = "X".repeat(url::capacity::URL_CAPACITY_LATEST);
+		assert!(matches!(Builder::new("").base_currency(base.as_str()).build(), Err(Error::UrlTooLong)));
+	}
+
+	#[test]
+	fn test_fill_capacity() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 2>::new();
+		rates.push(EUR, 0.8);
+		assert_eq!(rates.updated_at(), None);
+		assert!(fill(&mut rates, &mut [(EUR, 0.9), (USD, 1.0)].into_iter()).is_ok());
+		assert!(rates.updated_at().is_some());
+		let error = fill(&mut rates, &mut [(JPY, 149.5), (EUR, 0.95), (ILS, 3.1)].into_iter()).unwrap_err();
+		assert!(matches!(error, Error::CapacityExceeded { received: 3, stored: 1 }));
+		assert_eq!(rates.get(EUR), Some(&0.95));
+		assert_eq!(rates.len(), 2);
+	}
+
+	#[test]
+	fn test_parse_rates() {
+		use crate::{currency::*, scientific::ScientificBytesError};
+		let data = serde_json::from_str::<PayloadData>(r#"{"USD":{"value":1},"ILS":{"value":3.5e0}}"#).unwrap();
+		let mut rates = parse_rates::<f64>(&data).unwrap();
+		rates.sort_by_key(|&(currency, _)| currency);
+		assert_eq!(rates, [(ILS, 3.5), (USD, 1.0)]);
+		assert!(matches!(parse_rates::<i64>(&data), Err(Error::RateParseError { currency, error: ScientificBytesError::Parse(_) }) if currency == ILS));
+		let data = serde_json::from_str::<PayloadData>(r#"{"U":{"value":1}}"#).unwrap();
+		assert!(matches!(parse_rates::<f64>(&data), Err(Error::ResponseParseError)));
+	}
+
+	#[test]
+	fn test_missing() {
+		use crate::currency::*;
+		let data = serde_json::from_str::<PayloadData>(r#"{"USD":{"value":1},"ILS":{"value":3.5}}"#).unwrap();
+		assert!(missing(vec![], &data).is_empty());
+		assert!(missing(vec![ILS, USD], &data).is_empty());
+		assert_eq!(missing(vec![EUR, ILS, XAG, USD], &data), [EUR, XAG]);
+	}
+
+	fn metadata(etag: Option<&str>, last_modified: Option<&str>) -> Metadata<String, RateLimitPartial> {
+		Metadata {
+			last_updated_at: "2023-06-01T23:59:59Z".to_owned(),
+			rate_limit: RateLimitPartial { remaining_month: Some(300), ..Default::default() },
+			missing: vec![crate::currency::XAG],
+			etag: etag.map(str::to_owned),
+			last_modified: last_modified.map(str::to_owned),
+			modified: true,
+		}
+	}
+
+	#[test]
+	fn test_conditional_headers() {
+		let mut headers = HeaderMap::new();
+		insert_conditional_headers(&mut headers, &metadata(None, None));
+		assert!(headers.is_empty());
+
+		insert_conditional_headers(&mut headers, &metadata(Some("\"abc\""), Some("Thu, 01 Jun 2023 23:59:59 GMT")));
+		assert_eq!(headers[IF_NONE_MATCH], "\"abc\"");
+		assert_eq!(headers[IF_MODIFIED_SINCE], "Thu, 01 Jun 2023 23:59:59 GMT");
+
+		let mut headers = HeaderMap::new();
+		insert_conditional_headers(&mut headers, &metadata(Some("\"a\nb\""), Some("Thu, 01 Jun 2023 23:59:59 GMT")));
+		assert_eq!(headers.get(IF_NONE_MATCH), None);
+		assert_eq!(headers[IF_MODIFIED_SINCE], "Thu, 01 Jun 2023 23:59:59 GMT");
+	}
+
+	#[test]
+	fn test_not_modified() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 2>::try_from_iter([(USD, 1.0)]).unwrap();
+		let rate_limit = RateLimitPartial { remaining_month: Some(299), ..Default::default() };
+		let metadata = not_modified(&mut rates, metadata(Some("\"abc\""), None), rate_limit);
+		assert!(!metadata.modified);
+		assert_eq!(metadata.rate_limit, rate_limit);
+		assert_eq!(metadata.last_updated_at, "2023-06-01T23:59:59Z");
+		assert_eq!(metadata.missing, [XAG]);
+		assert_eq!(metadata.etag.as_deref(), Some("\"abc\""));
+		assert_eq!(metadata.last_modified, None);
+		assert!(rates.updated_at().is_some());
+		assert_eq!(rates.get(USD), Some(&1.0));
+	}
 }
@@ -6,18 +6,24 @@
 //! - [x] [Latest Exchange Rates](https://currencyapi.com/docs/latest) - [`Rates::fetch_latest`]
 //! - [ ] [Historical Exchange Rates](https://currencyapi.com/docs/historical)
 //!
+//! For simple fetches, [`Client`] bundles the API token with an HTTP client:
+//! ```ignore
+//! let client = Client::new(token);
+//! let (rates, metadata) = client.latest().currencies([EUR, USD]).fetch::<f64, 200>().await?;
+//! ```
+//!
 //! The [Convert Exchange Rates](https://currencyapi.com/docs/convert) endpoint is not provided but
-//! conversion is implemented via [`Rates::convert`].
+//! conversion is implemented via [`RateTable::convert`].
 //!
 //! ## Example
 //! ```ignore
 //! async fn main() {
 //!   let mut rates = Rates::<rust_decimal::Decimal>::new(); // requires `rust_decimal` feature and crate
-//!   let request = request.base_currency(EUR).currencies([EUR,USD,GBP]).build();
+//!   let request = request.base_currency(EUR).currencies([EUR,USD,GBP]).build().unwrap();
 //!   let metadata = rates
-//!   	.fetch_latest::<DateTime<Utc>, RateLimitIgnore>(&client, request) // DateTime<Utc> from the `chrono` crate
-//!   	.await
-//!   	.unwrap();
+//!     .fetch_latest::<DateTime<Utc>, RateLimitIgnore>(&client, request) // DateTime<Utc> from the `chrono` crate
+//!     .await
+//!     .unwrap();
 //!   println!("Fetched {} rates as of {}", rates.len(), metadata.last_updated_at);
 //!   for (currency, value) in rates.iter() { println!("{currency} {value}"); }
 //! }
@@ -27,22 +33,91 @@
 
 mod currency_impl;
 pub use currency_impl::{CurrencyCode, list as currency, Error as CurrencyError};
+mod currency_info;
+pub use currency_info::CurrencyInfo;
 mod url;
-pub mod latest;
+pub mod latest; pub use latest::Latest;
+pub mod client; pub use client::Client;
 
-mod rates;      pub use rates::Rates;
-mod scientific; pub use scientific::FromScientific;
-mod rate_limit; pub use rate_limit::{RateLimit, RateLimitIgnore};
+mod rates;      pub use rates::{Rates, RatesIntoIter, CapacityError, MergePolicy, MergeSummary, RateChange, RebaseError, SubsetError, ZeroRateError};
+mod rates_vec;  pub use rates_vec::RatesVec;
+mod csv;        pub use csv::CsvError;
+mod snapshot;   pub use snapshot::Snapshot;
+mod table;      pub use table::{RateTable, ConvertError};
+mod money;      pub use money::Money;
+mod scientific; pub use scientific::{FromScientific, IntegerError, ScientificBytesError};
+#[cfg(feature = "fixed")] pub use scientific::FixedError;
+mod round;      pub use round::{RoundableRate, RoundingMode};
+mod checked;    pub use checked::{CheckedRateOps, ArithmeticError};
+mod rate_limit; pub use rate_limit::{RateLimit, RateLimitIgnore, RateLimitPartial, RateLimitRaw};
 mod error;      pub use error::Error;
+mod token;      pub use token::Token;
 
 
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
 use rate_limit::RateLimitData;
+use url::UrlPart;
+
+impl<RATE> RatesVec<RATE> {
+    /// Fetches a [`latest`] [`Request`](latest::Request), without a capacity limit.
+    pub async fn fetch_latest<DateTime: FromStr, RateLimit: for<'x> RateLimitData<'x>>(&mut self, client: &reqwest::Client, request: latest::Request) -> Result<latest::Metadata<DateTime, RateLimit>, Error> where RATE: FromScientific<Error: fmt::Display> {
+        request.send_vec::<DateTime, RATE, RateLimit>(self, client).await
+    }
+}
 
 impl<const N: usize, RATE> Rates<RATE, N> {
     /// Fetches a [`latest`] [`Request`](latest::Request).
-    pub async fn fetch_latest<DateTime: FromStr, RateLimit: for<'x> RateLimitData<'x>>(&mut self, client: &reqwest::Client, request: latest::Request) -> Result<latest::Metadata<DateTime, RateLimit>, Error> where RATE: FromScientific {
+    pub async fn fetch_latest<DateTime: FromStr, RateLimit: for<'x> RateLimitData<'x>>(&mut self, client: &reqwest::Client, request: latest::Request) -> Result<latest::Metadata<DateTime, RateLimit>, Error> where RATE: FromScientific<Error: fmt::Display> {
         request.send::<N, DateTime, RATE, RateLimit>(self, client).await
     }
+
+    /// Fetches a [`latest`] request, split into as many requests as needed to fit its currencies.
+    ///
+    /// Unlike a single [`Request`](latest::Request), any number of currencies can be requested. The requests are sent
+    /// sequentially, and a currency requested more than once is only fetched once. Returns the metadata of the last
+    /// request, with the [`missing`](latest::Metadata::missing) currencies of all of them.
+    ///
+    /// If a request fails, its error is returned after storing the rates of the requests before it, and the missing
+    /// currencies gathered so far are dropped.
+    pub async fn fetch_latest_chunked<DateTime: FromStr, RateLimit: for<'x> RateLimitData<'x>, Currencies: IntoIterator<Item = CurrencyCode>, BaseCurrency: UrlPart + Clone>(
+        &mut self,
+        client: &reqwest::Client,
+        builder: latest::Builder<'_, Currencies, BaseCurrency>,
+    ) -> Result<latest::Metadata<DateTime, RateLimit>, Error> where RATE: FromScientific<Error: fmt::Display> {
+        let mut missing = Vec::new();
+        let mut metadata = None;
+        for chunk in builder.chunked() {
+            let mut chunk_metadata = self.fetch_latest::<DateTime, RateLimit>(client, chunk.build()?).await?;
+            missing.append(&mut chunk_metadata.missing);
+            metadata = Some(chunk_metadata);
+        }
+        // chunked always yields at least one builder.
+        let mut metadata = metadata.unwrap();
+        metadata.missing = missing;
+        Ok(metadata)
+    }
+
+    /// Fetches [`latest`] rates relative to each of the given base currencies, concurrently.
+    ///
+    /// The [`base_currency`](latest::Builder::base_currency) of the builder is replaced by each of the bases in turn.
+    /// Every base currency is a separate request, so this multiplies the quota usage by their count.
+    pub async fn fetch_latest_bases<Currencies: IntoIterator<Item = CurrencyCode> + Clone, BaseCurrency>(
+        client: &reqwest::Client,
+        builder: latest::Builder<'_, Currencies, BaseCurrency>,
+        bases: impl IntoIterator<Item = CurrencyCode>,
+    ) -> Result<Vec<(CurrencyCode, Self)>, Error> where RATE: FromScientific<Error: fmt::Display> {
+        let requests = bases.into_iter().map(|base| {
+            let request = latest::Builder::new(builder.token.borrowed())
+                .base_currency(base)
+                .currencies(builder.currencies.clone())
+                .build();
+            async move {
+                let mut rates = Self::new();
+                rates.fetch_latest::<String, RateLimitIgnore>(client, request?).await?;
+                Ok((base, rates))
+            }
+        });
+        futures::future::join_all(requests).await.into_iter().collect()
+    }
 }
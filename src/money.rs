@@ -0,0 +1,60 @@
+//! [`Money`]
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::CurrencyCode;
+
+/// An amount of a currency.
+#[derive(Debug, Hash, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money<RATE> {
+	/// The amount.
+	pub amount: RATE,
+	/// The currency of the amount.
+	pub currency: CurrencyCode,
+}
+
+impl<RATE> Money<RATE> {
+	/// Creates a new [`Money`] value.
+	#[inline] pub const fn new(amount: RATE, currency: CurrencyCode) -> Self { Self { amount, currency } }
+}
+
+impl<RATE> From<(CurrencyCode, RATE)> for Money<RATE> {
+	#[inline] fn from((currency, amount): (CurrencyCode, RATE)) -> Self { Self::new(amount, currency) }
+}
+
+impl<RATE> From<Money<RATE>> for (CurrencyCode, RATE) {
+	#[inline] fn from(money: Money<RATE>) -> Self { (money.currency, money.amount) }
+}
+
+/// Displays the amount followed by the currency code, e.g. `12.50 USD`.
+///
+/// The formatting options apply to the amount, e.g. `{:.2}` for two decimal places.
+impl<RATE: Display> Display for Money<RATE> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.amount, f)?;
+		write!(f, " {}", self.currency)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{currency::*, ConvertError, Rates, RateTable};
+
+	#[test]
+	fn test_money() {
+		let money = Money::new(12.5, USD);
+		assert_eq!(format!("{money:.2}"), "12.50 USD");
+		assert_eq!(Money::from((EUR, 3.0)), Money::new(3.0, EUR));
+
+		let json = serde_json::to_string(&money).unwrap();
+		assert_eq!(json, r#"{"amount":12.5,"currency":"USD"}"#);
+		assert_eq!(serde_json::from_str::<Money<f64>>(&json).unwrap(), money);
+
+		let rates = Rates::<f64, 2>::try_from_iter([(USD, 1.0), (ILS, 4.0)]).unwrap();
+		assert_eq!(rates.convert_money(money, ILS), Ok(Money::new(50.0, ILS)));
+		assert_eq!(rates.convert_money(money, EUR), Err(ConvertError::MissingTo(EUR)));
+	}
+}
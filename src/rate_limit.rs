@@ -1,6 +1,8 @@
 //! [`RateLimit`]
 
-use std::convert::Infallible;
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::HeaderMap;
 
 /// [Rate-limit data](https://currencyapi.com/docs/#rate-limit-and-quotas) from response headers.
 #[derive(Debug, Hash, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
@@ -9,38 +11,174 @@ pub struct RateLimit {
 	pub limit_minute: usize,
 	/// How many requests can be made in a month.
 	pub limit_month: usize,
-	/// How many remaining requests can be made in the minute of request.
+	/// How many remaining requests can be made in the minute of request, see [`RateLimit::remaining_minute`].
 	pub remainig_minute: usize,
 	/// How many remaining requests can be made in the month of request.
 	pub remaining_month: usize,
+	/// When the minute quota resets, if the response says.
+	pub reset_minute: Option<SystemTime>,
+	/// When the month quota resets, if the response says.
+	pub reset_month: Option<SystemTime>,
+}
+
+/// [Rate-limit data](https://currencyapi.com/docs/#rate-limit-and-quotas) from response headers, like [`RateLimit`],
+/// but with whichever headers are present, e.g. behind proxies that strip some of them.
+#[derive(Debug, Hash, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+pub struct RateLimitPartial {
+	/// How many requests can be made in a minute.
+	pub limit_minute: Option<usize>,
+	/// How many requests can be made in a month.
+	pub limit_month: Option<usize>,
+	/// How many remaining requests can be made in the minute of request.
+	pub remaining_minute: Option<usize>,
+	/// How many remaining requests can be made in the month of request.
+	pub remaining_month: Option<usize>,
+	/// When the minute quota resets, if the response says.
+	pub reset_minute: Option<SystemTime>,
+	/// When the month quota resets, if the response says.
+	pub reset_month: Option<SystemTime>,
+}
+
+/// The raw [rate-limit](https://currencyapi.com/docs/#rate-limit-and-quotas) response headers, as-is, e.g. to log
+/// them when [`RateLimit`] fails to parse them.
+///
+/// Header values that aren't valid UTF-8 are converted lossily.
+#[derive(Debug, Hash, Default, Clone, PartialEq, Eq)]
+pub struct RateLimitRaw {
+	/// The `X-RateLimit-Limit-Quota-Minute` header.
+	pub limit_minute: Option<String>,
+	/// The `X-RateLimit-Limit-Quota-Month` header.
+	pub limit_month: Option<String>,
+	/// The `X-RateLimit-Remaining-Quota-Minute` header.
+	pub remaining_minute: Option<String>,
+	/// The `X-RateLimit-Remaining-Quota-Month` header.
+	pub remaining_month: Option<String>,
+	/// The `X-RateLimit-Reset-Quota-Minute` header.
+	pub reset_minute: Option<String>,
+	/// The `X-RateLimit-Reset-Quota-Month` header.
+	pub reset_month: Option<String>,
 }
 
 /// Ignore rate limit data.
 pub struct RateLimitIgnore;
 
-impl TryFrom<&reqwest::Response> for RateLimit {
+const LIMIT_MINUTE: &str = "X-RateLimit-Limit-Quota-Minute";
+const LIMIT_MONTH: &str = "X-RateLimit-Limit-Quota-Month";
+const REMAINING_MINUTE: &str = "X-RateLimit-Remaining-Quota-Minute";
+const REMAINING_MONTH: &str = "X-RateLimit-Remaining-Quota-Month";
+const RESET_MINUTE: &str = "X-RateLimit-Reset-Quota-Minute";
+const RESET_MONTH: &str = "X-RateLimit-Reset-Quota-Month";
+
+impl RateLimit {
+	/// Gets the raw rate-limit headers of a response, see [`RateLimitRaw`].
+	#[inline] pub fn from_response_raw(response: &reqwest::Response) -> RateLimitRaw { response.into() }
+
+	/// Gets how many remaining requests can be made in the minute of request.
+	///
+	/// Prefer this over the misspelled [`remainig_minute`](RateLimit::remainig_minute) field, which is kept for
+	/// compatibility and will be deprecated, then renamed in the next major version.
+	#[inline] pub const fn remaining_minute(&self) -> usize { self.remainig_minute }
+
+	/// Gets how many requests were made in the minute of request.
+	#[inline] pub const fn consumed_minute(&self) -> usize { self.limit_minute.saturating_sub(self.remainig_minute) }
+	/// Gets how many requests were made in the month of request.
+	#[inline] pub const fn consumed_month(&self) -> usize { self.limit_month.saturating_sub(self.remaining_month) }
+
+	/// Gets the fraction of the minute's requests that remain, from 0 to 1, or 0 if the limit is 0.
+	#[inline] pub fn fraction_remaining_minute(&self) -> f32 { fraction(self.remainig_minute, self.limit_minute) }
+	/// Gets the fraction of the month's requests that remain, from 0 to 1, or 0 if the limit is 0.
+	#[inline] pub fn fraction_remaining_month(&self) -> f32 { fraction(self.remaining_month, self.limit_month) }
+}
+
+/// Gets `remaining / limit`, clamped to 0 to 1, or 0 if the limit is 0.
+fn fraction(remaining: usize, limit: usize) -> f32 {
+	if limit == 0 { return 0.0 }
+	(remaining as f32 / limit as f32).min(1.0)
+}
+
+impl RateLimitRaw {
+	fn from_headers(headers: &HeaderMap) -> Self {
+		let h = |name| headers.get(name).map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned());
+		Self {
+			limit_minute: h(LIMIT_MINUTE),
+			limit_month: h(LIMIT_MONTH),
+			remaining_minute: h(REMAINING_MINUTE),
+			remaining_month: h(REMAINING_MONTH),
+			reset_minute: h(RESET_MINUTE),
+			reset_month: h(RESET_MONTH),
+		}
+	}
+}
+
+impl From<&reqwest::Response> for RateLimitRaw {
+	#[inline] fn from(value: &reqwest::Response) -> Self { Self::from_headers(value.headers()) }
+}
+
+impl RateLimitPartial {
+	fn from_headers(headers: &HeaderMap, now: SystemTime) -> Self {
+		let h = |name| headers.get(name).and_then(|value| atoi::atoi(value.as_bytes()));
+		let reset = |name| headers.get(name).and_then(|value| atoi::atoi(value.as_bytes())).and_then(|seconds| reset_time(seconds, now));
+		Self {
+			limit_minute: h(LIMIT_MINUTE),
+			limit_month: h(LIMIT_MONTH),
+			remaining_minute: h(REMAINING_MINUTE),
+			remaining_month: h(REMAINING_MONTH),
+			reset_minute: reset(RESET_MINUTE),
+			reset_month: reset(RESET_MONTH),
+		}
+	}
+}
+
+impl From<&reqwest::Response> for RateLimitPartial {
+	#[inline] fn from(value: &reqwest::Response) -> Self { Self::from_headers(value.headers(), SystemTime::now()) }
+}
+
+/// Interprets a reset header's seconds, as a Unix timestamp if large enough to be one, or as a delta from `now`.
+///
+/// Returns [`None`] if the time overflows, as though the header were absent.
+fn reset_time(seconds: u64, now: SystemTime) -> Option<SystemTime> {
+	/// Deltas are at most a month, while timestamps are well past this (2001).
+	const TIMESTAMP_MIN: u64 = 1_000_000_000;
+	let seconds = Duration::from_secs(seconds);
+	if seconds.as_secs() >= TIMESTAMP_MIN { SystemTime::UNIX_EPOCH.checked_add(seconds) } else { now.checked_add(seconds) }
+}
+
+impl From<RateLimit> for RateLimitPartial {
+	fn from(value: RateLimit) -> Self {
+		Self {
+			limit_minute: Some(value.limit_minute),
+			limit_month: Some(value.limit_month),
+			remaining_minute: Some(value.remainig_minute),
+			remaining_month: Some(value.remaining_month),
+			reset_minute: value.reset_minute,
+			reset_month: value.reset_month,
+		}
+	}
+}
+
+/// Fails if any of the quota data is missing, while the reset times are optional either way.
+impl TryFrom<RateLimitPartial> for RateLimit {
 	type Error = ();
 
-	fn try_from(value: &reqwest::Response) -> Result<Self, Self::Error> {
-		let headers = value.headers();
-		let h = |name| {
-			headers
-				.get(name)
-				.ok_or(())
-				.and_then(|value| atoi::atoi(value.as_bytes()).ok_or(()))
-		};
+	fn try_from(value: RateLimitPartial) -> Result<Self, Self::Error> {
 		Ok(Self {
-			limit_minute: h("X-RateLimit-Limit-Quota-Minute")?,
-			limit_month: h("X-RateLimit-Limit-Quota-Month")?,
-			remainig_minute: h("X-RateLimit-Remaining-Quota-Minute")?,
-			remaining_month: h("X-RateLimit-Remaining-Quota-Month")?,
+			limit_minute: value.limit_minute.ok_or(())?,
+			limit_month: value.limit_month.ok_or(())?,
+			remainig_minute: value.remaining_minute.ok_or(())?,
+			remaining_month: value.remaining_month.ok_or(())?,
+			reset_minute: value.reset_minute,
+			reset_month: value.reset_month,
 		})
 	}
 }
 
-impl TryFrom<&reqwest::Response> for RateLimitIgnore {
-	type Error = Infallible;
-	#[inline] fn try_from(_: &reqwest::Response) -> Result<Self, Self::Error> { Ok(RateLimitIgnore) }
+impl TryFrom<&reqwest::Response> for RateLimit {
+	type Error = ();
+	#[inline] fn try_from(value: &reqwest::Response) -> Result<Self, Self::Error> { RateLimitPartial::from(value).try_into() }
+}
+
+impl From<&reqwest::Response> for RateLimitIgnore {
+	#[inline] fn from(_: &reqwest::Response) -> Self { RateLimitIgnore }
 }
 
 
@@ -48,9 +186,80 @@ mod private {
 	use super::*;
 	pub trait Sealed<'a>: TryFrom<&'a reqwest::Response> {}
 	impl<'a> Sealed<'a> for RateLimit {}
+	impl<'a> Sealed<'a> for RateLimitPartial {}
+	impl<'a> Sealed<'a> for RateLimitRaw {}
 	impl<'a> Sealed<'a> for RateLimitIgnore {}
 }
 
 pub trait RateLimitData<'a>: private::Sealed<'a> {}
 impl<'a> RateLimitData<'a> for RateLimit {}
+impl<'a> RateLimitData<'a> for RateLimitPartial {}
+impl<'a> RateLimitData<'a> for RateLimitRaw {}
 impl<'a> RateLimitData<'a> for RateLimitIgnore {}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	#[test]
+	fn test_partial() {
+		let mut headers = HeaderMap::new();
+		headers.insert("X-RateLimit-Limit-Quota-Minute", HeaderValue::from_static("10"));
+		headers.insert("X-RateLimit-Remaining-Quota-Minute", HeaderValue::from_static("9"));
+		headers.insert("X-RateLimit-Limit-Quota-Month", HeaderValue::from_static("many"));
+		let partial = RateLimitPartial::from_headers(&headers, SystemTime::UNIX_EPOCH);
+		assert_eq!(partial, RateLimitPartial { limit_minute: Some(10), remaining_minute: Some(9), ..Default::default() });
+		assert_eq!(RateLimit::try_from(partial), Err(()));
+
+		headers.insert("X-RateLimit-Limit-Quota-Month", HeaderValue::from_static("300"));
+		headers.insert("X-RateLimit-Remaining-Quota-Month", HeaderValue::from_static("200"));
+		let strict = RateLimit { limit_minute: 10, limit_month: 300, remainig_minute: 9, remaining_month: 200, ..Default::default() };
+		assert_eq!(RateLimit::try_from(RateLimitPartial::from_headers(&headers, SystemTime::UNIX_EPOCH)), Ok(strict));
+		assert_eq!(RateLimitPartial::from(strict), RateLimitPartial::from_headers(&headers, SystemTime::UNIX_EPOCH));
+	}
+
+	#[test]
+	fn test_raw() {
+		let mut headers = HeaderMap::new();
+		headers.insert(LIMIT_MINUTE, HeaderValue::from_static("10"));
+		headers.insert(REMAINING_MONTH, HeaderValue::from_static("many"));
+		headers.insert(RESET_MONTH, HeaderValue::from_bytes(b"\xff1").unwrap());
+		assert_eq!(RateLimitRaw::from_headers(&headers), RateLimitRaw {
+			limit_minute: Some("10".into()),
+			remaining_month: Some("many".into()),
+			reset_month: Some("\u{FFFD}1".into()),
+			..Default::default()
+		});
+	}
+
+	#[test]
+	fn test_arithmetic() {
+		let rate_limit = RateLimit { limit_minute: 10, limit_month: 300, remainig_minute: 4, remaining_month: 300, ..Default::default() };
+		assert_eq!(rate_limit.remaining_minute(), 4);
+		assert_eq!(rate_limit.consumed_minute(), 6);
+		assert_eq!(rate_limit.consumed_month(), 0);
+		assert_eq!(rate_limit.fraction_remaining_minute(), 0.4);
+		assert_eq!(rate_limit.fraction_remaining_month(), 1.0);
+
+		let rate_limit = RateLimit { limit_minute: 0, remainig_minute: 5, ..Default::default() };
+		assert_eq!(rate_limit.consumed_minute(), 0);
+		assert_eq!(rate_limit.fraction_remaining_minute(), 0.0);
+		assert_eq!(rate_limit.fraction_remaining_month(), 0.0);
+		assert_eq!(RateLimit { limit_month: 1, remaining_month: 2, ..Default::default() }.fraction_remaining_month(), 1.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let mut headers = HeaderMap::new();
+		assert_eq!(RateLimitPartial::from_headers(&headers, now).reset_minute, None);
+		headers.insert("X-RateLimit-Reset-Quota-Minute", HeaderValue::from_static("42"));
+		headers.insert("X-RateLimit-Reset-Quota-Month", HeaderValue::from_static("1702000000"));
+		let partial = RateLimitPartial::from_headers(&headers, now);
+		assert_eq!(partial.reset_minute, Some(now + Duration::from_secs(42)));
+		assert_eq!(partial.reset_month, Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_702_000_000)));
+		headers.insert("X-RateLimit-Reset-Quota-Month", HeaderValue::from_static("18446744073709551615"));
+		assert_eq!(RateLimitPartial::from_headers(&headers, now).reset_month, None);
+	}
+}
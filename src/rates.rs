@@ -1,14 +1,23 @@
 //! Currency rates container.
 
-use std::{mem::{MaybeUninit, self}, fmt, ops::{Div, Mul}};
+use std::{collections::{BTreeMap, HashMap}, hash::{DefaultHasher, Hash, Hasher}, convert::Infallible, mem::{MaybeUninit, self}, fmt, marker::PhantomData, ops::{Div, Index, Mul, MulAssign, Sub}, ptr, time::{Duration, SystemTime}};
 
-use crate::CurrencyCode;
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{CurrencyCode, FromScientific, RateTable, RoundableRate, RoundingMode};
 
 /// Currency rates.
+///
+/// Holds up to `N` rates inline. By default, `N` fits every [known currency](crate::currency::ARRAY) with some slack
+/// for currencies the API adds later, so `Rates::<f64>::new()` can hold a fetch of all currencies.
 pub struct Rates<RATE, const N: usize = { crate::currency::ARRAY.len() + /* slack */ 10 }> {
 	currency: [MaybeUninit<CurrencyCode>; N],
 	rate: [MaybeUninit<RATE>; N],
-	len: u8,
+	len: usize,
+	/// Whether the currencies are sorted, enabling binary search.
+	sorted: bool,
+	/// When the rates were fetched, if known.
+	updated_at: Option<SystemTime>,
 }
 
 impl<const N: usize, RATE> Rates<RATE, N> {
@@ -20,20 +29,129 @@ impl<const N: usize, RATE> Rates<RATE, N> {
 			MaybeUninit::<[MaybeUninit<RATE>; N]>::uninit().assume_init()
 		},
 		len: 0,
+		sorted: true,
+		updated_at: None,
 	} }
 
+	/// Creates a new [`Rates`] value from an array of rates, usable in const contexts:
+	/// ```
+	/// # use currencyapi::{currency::*, Rates};
+	/// const RATES: Rates<f64, 3> = Rates::from_entries([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]);
+	/// ```
+	///
+	/// Fails to compile if there are more than `N` entries.
+	pub const fn from_entries<const M: usize>(entries: [(CurrencyCode, RATE); M]) -> Self {
+		const { assert!(M <= N, "the entries should fit in the capacity") };
+		let mut rates = Self::new();
+		let entries = mem::ManuallyDrop::new(entries);
+		let entries = &entries as *const mem::ManuallyDrop<[(CurrencyCode, RATE); M]> as *const (CurrencyCode, RATE);
+		let mut i = 0;
+		while i < M {
+			// SAFETY: each entry is moved out once, and the array isn't dropped.
+			let entry = unsafe { entries.add(i) };
+			let currency = unsafe { (&raw const (*entry).0).read() };
+			let rate = unsafe { (&raw const (*entry).1).read() };
+			if i > 0 {
+				// SAFETY: 0..i are initialized.
+				let previous = unsafe { rates.currency[i - 1].assume_init() };
+				rates.sorted &= !previous.const_cmp(currency).is_gt();
+			}
+			rates.currency[i] = MaybeUninit::new(currency);
+			rates.rate[i] = MaybeUninit::new(rate);
+			i += 1;
+		}
+		rates.len = M;
+		rates
+	}
+
+	/// Creates a new [`Rates`] value on the heap, without constructing it on the stack first.
+	///
+	/// Prefer this over boxing [`Rates::new`] when `N` or `RATE` are large enough to risk a stack overflow.
+	pub fn new_boxed() -> Box<Self> {
+		let mut rates = Box::<Self>::new_uninit();
+		unsafe {
+			// SAFETY: the arrays are of MaybeUninit so need no initialization, and the rest is initialized here.
+			let ptr = rates.as_mut_ptr();
+			(&raw mut (*ptr).len).write(0);
+			(&raw mut (*ptr).sorted).write(true);
+			(&raw mut (*ptr).updated_at).write(None);
+			rates.assume_init()
+		}
+	}
+
 	/// Gets the count of rates.
-	#[inline] pub const fn len(&self) -> usize { self.len as usize }
+	#[inline] pub const fn len(&self) -> usize { self.len }
 	/// Gets whether there are no rates.
 	#[inline] pub const fn is_empty(&self) -> bool { self.len == 0 }
+	/// Gets the maximum count of rates, `N`.
+	#[inline] pub const fn capacity(&self) -> usize { N }
+	/// Gets how many more rates can be pushed.
+	#[inline] pub const fn remaining(&self) -> usize { N - self.len }
+	/// Gets whether no more rates can be pushed.
+	#[inline] pub const fn is_full(&self) -> bool { self.len == N }
 	/// Removes all rates.
-	#[inline] pub fn clear(&mut self) { self.len = 0; }
+	pub fn clear(&mut self) {
+		self.truncate(0);
+		self.sorted = true;
+	}
+
+	/// Keeps the first `len` rates and drops the rest, if there are more.
+	pub fn truncate(&mut self, len: usize) {
+		let Some(count) = self.len.checked_sub(len) else { return };
+		self.len = len;
+		unsafe {
+			// SAFETY: len..len+count are initialized, and are excluded by the new len before dropping, so that
+			// should a drop panic, the remaining rates leak rather than get dropped twice.
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.rate.as_mut_ptr().cast::<RATE>().add(len), count));
+		}
+	}
+
+	/// Moves the rates from index `at` onward into a new [`Rates`], keeping the first `at`.
+	///
+	/// # Panics
+	/// If `at` > [`Rates::len`].
+	#[track_caller]
+	pub fn split_off(&mut self, at: usize) -> Self {
+		assert!(at <= self.len, "split index ({at}) should be <= len ({})", self.len);
+		let count = self.len - at;
+		let mut tail = Self::new();
+		unsafe {
+			// SAFETY: at..len are initialized, and are moved out by excluding them from the len.
+			ptr::copy_nonoverlapping(self.currency.as_ptr().add(at), tail.currency.as_mut_ptr(), count);
+			ptr::copy_nonoverlapping(self.rate.as_ptr().add(at), tail.rate.as_mut_ptr(), count);
+		}
+		self.len = at;
+		tail.len = count;
+		tail.sorted = self.sorted;
+		tail.updated_at = self.updated_at;
+		tail
+	}
+
+	/// Gets when the rates were fetched, if known, see [`Rates::set_updated_at`].
+	#[inline] pub const fn updated_at(&self) -> Option<SystemTime> { self.updated_at }
+
+	/// Sets when the rates were fetched, e.g. when restoring them from a [`Snapshot`](crate::Snapshot).
+	///
+	/// Fetching rates sets it to the time of the fetch.
+	#[inline] pub fn set_updated_at(&mut self, updated_at: Option<SystemTime>) { self.updated_at = updated_at; }
+
+	/// Gets how long before `now` the rates were fetched, if known, see [`Rates::updated_at`].
+	///
+	/// Returns zero if they were fetched after `now`, e.g. due to clock adjustments.
+	pub fn age(&self, now: SystemTime) -> Option<Duration> {
+		self.updated_at.map(|updated_at| now.duration_since(updated_at).unwrap_or_default())
+	}
+
+	/// Gets whether the rates were fetched more than `max_age` ago, or when is unknown.
+	pub fn is_stale(&self, max_age: Duration) -> bool {
+		self.age(SystemTime::now()).is_none_or(|age| age > max_age)
+	}
 
 	/// Gets a slice of the currencies.
 	pub fn currencies(&self) -> &[CurrencyCode] {
 		unsafe {
 			// SAFETY: self.len keeps us safe.
-			let currencies = self.currency.get_unchecked(..self.len as usize);
+			let currencies = self.currency.get_unchecked(..self.len);
 			// SAFETY: valid per MaybeUninit docs (array example).
 			mem::transmute::<
 				&[MaybeUninit<CurrencyCode>],
@@ -46,7 +164,7 @@ impl<const N: usize, RATE> Rates<RATE, N> {
 	pub fn rates(&self) -> &[RATE] {
 		unsafe {
 			// SAFETY: self.len keeps us safe.
-			let rates = self.rate.get_unchecked(..self.len as usize);
+			let rates = self.rate.get_unchecked(..self.len);
 			// SAFETY: valid per MaybeUninit docs (array example).
 			mem::transmute::<
 				&[MaybeUninit<RATE>],
@@ -55,17 +173,71 @@ impl<const N: usize, RATE> Rates<RATE, N> {
 		}
 	}
 
+	/// Gets mutable slices of the currencies and the rates.
+	fn slices_mut(&mut self) -> (&mut [CurrencyCode], &mut [RATE]) {
+		let len = self.len;
+		unsafe {
+			// SAFETY: len keeps us safe, and the transmutes are valid per MaybeUninit docs (array example).
+			(
+				mem::transmute::<&mut [MaybeUninit<CurrencyCode>], &mut [CurrencyCode]>(self.currency.get_unchecked_mut(..len)),
+				mem::transmute::<&mut [MaybeUninit<RATE>], &mut [RATE]>(self.rate.get_unchecked_mut(..len)),
+			)
+		}
+	}
+
+	/// Gets a mutable slice of the rates, e.g. to scale them all in place.
+	pub fn rates_mut(&mut self) -> &mut [RATE] { self.slices_mut().1 }
+
 	/// Iterates over currency rates.
 	pub fn iter(&self) -> impl Iterator<Item = (CurrencyCode, &RATE)> {
 		self.currencies().iter().copied().zip(self.rates().iter()).rev()
 	}
 
+	/// Iterates over clones of the currency rates as pairs, in storage order (unlike [`Rates::iter`]).
+	pub fn to_pairs(&self) -> impl Iterator<Item = (CurrencyCode, RATE)> + '_ where RATE: Clone {
+		self.currencies().iter().copied().zip(self.rates().iter().cloned())
+	}
+
+	/// Copies the currency rates as pairs into `pairs`, in storage order, returning how many were copied.
+	///
+	/// Copies no more than fit, i.e. the lesser of [`Rates::len`] and `pairs.len()`.
+	pub fn copy_to_pairs(&self, pairs: &mut [(CurrencyCode, RATE)]) -> usize where RATE: Clone {
+		let mut count = 0;
+		for (pair, (currency, rate)) in pairs.iter_mut().zip(self.currencies().iter().zip(self.rates())) {
+			pair.0 = *currency;
+			pair.1.clone_from(rate);
+			count += 1;
+		}
+		count
+	}
+
+	/// Iterates over the rates in use, i.e. without those superseded by a later push, in insertion order.
+	pub(crate) fn entries(&self) -> impl Iterator<Item = (CurrencyCode, &RATE)> {
+		self.currencies().iter().copied().zip(self.rates()).enumerate()
+			.filter(|&(i, (currency, _))| self.index_of(currency) == Some(i))
+			.map(|(_, entry)| entry)
+	}
+
+	/// Collects the rates in use, ordered alphabetically by currency.
+	fn alphabetical_entries(&self) -> Vec<(CurrencyCode, &RATE)> {
+		let mut entries = self.entries().collect::<Vec<_>>();
+		entries.sort_unstable_by_key(|&(currency, _)| currency);
+		entries
+	}
+
+	/// Iterates over currency rates, mutably.
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = (CurrencyCode, &mut RATE)> {
+		let (currencies, rates) = self.slices_mut();
+		currencies.iter().copied().zip(rates.iter_mut()).rev()
+	}
+
 	/// Pushes a new currency rate. See [`Rates::push`].
 	///
 	/// # Safety
 	/// Ensure there is space for the new rate, i.e. that [`Rates::len`] < `N`.
 	pub unsafe fn push_unchecked(&mut self, currency: CurrencyCode, rate: RATE) {
-		let i = self.len as usize;
+		let i = self.len;
+		self.sorted &= self.currencies().last().is_none_or(|&last| last <= currency);
 		*self.currency.get_unchecked_mut(i) = MaybeUninit::new(currency);
 		*self.rate.get_unchecked_mut(i) = MaybeUninit::new(rate);
 		self.len += 1;
@@ -76,82 +248,1837 @@ impl<const N: usize, RATE> Rates<RATE, N> {
 	/// Does not check for duplicates, but other functions should
 	/// use the latest pushed rate of a currency.
 	///
-	/// Returns whether the rate was inserted.
+	/// Pushing a currency that is ordered before the last one invalidates the [sortedness](Rates::sort).
+	///
+	/// Returns whether the rate was inserted. Prefer [`Rates::try_push`], which hands back a rate that didn't fit.
 	pub fn push(&mut self, currency: CurrencyCode, rate: RATE) -> bool {
-		if (self.len as usize) < N {
+		self.try_push(currency, rate).is_ok()
+	}
+
+	/// Pushes a new currency rate, if the [`Rates`] is not full, see [`Rates::push`].
+	pub fn try_push(&mut self, currency: CurrencyCode, rate: RATE) -> Result<(), CapacityError<RATE>> {
+		if self.len < N {
+			unsafe {
+				// SAFETY: there's space in this branch
+				self.push_unchecked(currency, rate);
+			}
+			Ok(())
+		} else { Err(CapacityError { currency, rate }) }
+	}
+
+	/// Sets the rate of a currency, replacing its existing rate or pushing it otherwise.
+	///
+	/// Returns the replaced rate, or hands the given rate back if the currency is new and the [`Rates`] is full.
+	pub fn insert_or_update(&mut self, currency: CurrencyCode, rate: RATE) -> Result<Option<RATE>, RATE> {
+		if let Some(existing) = self.get_mut(currency) {
+			Ok(Some(mem::replace(existing, rate)))
+		} else if self.len < N {
 			unsafe {
 				// SAFETY: there's space in this branch
 				self.push_unchecked(currency, rate);
 			}
-			true
-		} else { false }
+			Ok(None)
+		} else { Err(rate) }
+	}
+
+	/// Gets the rate of a currency mutably, pushing the given default rate first if the currency is new.
+	///
+	/// # Panics
+	/// If the currency is new and the [`Rates`] is full, see [`Rates::try_get_or_insert`] for a non-panicking version.
+	pub fn get_or_insert(&mut self, currency: CurrencyCode, default: RATE) -> &mut RATE {
+		match self.try_get_or_insert(currency, default) {
+			Ok(rate) => rate,
+			Err(_) => panic!("no capacity for the {currency} rate"),
+		}
+	}
+
+	/// Gets the rate of a currency mutably, pushing the given default rate first if the currency is new.
+	///
+	/// Hands the default rate back if the currency is new and the [`Rates`] is full.
+	pub fn try_get_or_insert(&mut self, currency: CurrencyCode, default: RATE) -> Result<&mut RATE, RATE> {
+		let i = match self.index_of(currency) {
+			Some(i) => i,
+			None if self.len < N => {
+				unsafe {
+					// SAFETY: there's space in this branch
+					self.push_unchecked(currency, default);
+				}
+				self.len - 1
+			},
+			None => return Err(default),
+		};
+		Ok(&mut self.rates_mut()[i])
+	}
+
+	/// Converts the rates to another type, keeping their currencies and order.
+	pub fn map_rates<NEW>(self, mut f: impl FnMut(RATE) -> NEW) -> Rates<NEW, N> {
+		match self.try_map_rates(|rate| Ok::<_, Infallible>(f(rate))) {
+			Ok(rates) => rates,
+			Err((_, never)) => match never {},
+		}
+	}
+
+	/// Converts the rates to another type, like [`Rates::map_rates`], without consuming the [`Rates`].
+	pub fn map_rates_ref<NEW>(&self, mut f: impl FnMut(&RATE) -> NEW) -> Rates<NEW, N> {
+		let mut rates = Rates::new();
+		for (&currency, rate) in self.currencies().iter().zip(self.rates()) {
+			unsafe {
+				// SAFETY: self has no more than N rates.
+				rates.push_unchecked(currency, f(rate));
+			}
+		}
+		rates.updated_at = self.updated_at;
+		rates
+	}
+
+	/// Converts the rates to another type fallibly, like [`Rates::map_rates`].
+	///
+	/// Fails with the first currency whose rate failed to convert, and the conversion error.
+	pub fn try_map_rates<NEW, E>(self, mut f: impl FnMut(RATE) -> Result<NEW, E>) -> Result<Rates<NEW, N>, (CurrencyCode, E)> {
+		let mut rates = Rates::new();
+		rates.updated_at = self.updated_at;
+		for (currency, rate) in self.into_iter().rev() {
+			let rate = f(rate).map_err(|e| (currency, e))?;
+			unsafe {
+				// SAFETY: self has no more than N rates.
+				rates.push_unchecked(currency, rate);
+			}
+		}
+		Ok(rates)
+	}
+
+	/// Clones the rates of the given currencies into a new [`Rates`], in the given order (skipping repeated currencies).
+	///
+	/// Fails if any of the currencies are missing, or if there are more than `M` of them.
+	pub fn subset<const M: usize>(&self, currencies: &[CurrencyCode]) -> Result<Rates<RATE, M>, SubsetError> where RATE: Clone {
+		let mut missing = Vec::new();
+		for &currency in currencies {
+			if !self.contains(currency) && !missing.contains(&currency) { missing.push(currency); }
+		}
+		if !missing.is_empty() { return Err(SubsetError::Missing(missing)); }
+		let mut subset = Rates::new();
+		subset.updated_at = self.updated_at;
+		for &currency in currencies {
+			if subset.contains(currency) { continue }
+			subset.try_push(currency, self[currency].clone()).map_err(|_| SubsetError::Capacity { capacity: M })?;
+		}
+		Ok(subset)
+	}
+
+	/// Moves the rates in use into a [`HashMap`].
+	pub fn into_hash_map(self) -> HashMap<CurrencyCode, RATE> {
+		// in insertion order, so that superseded rates are overwritten.
+		self.into_iter().rev().collect()
+	}
+
+	/// Moves the rates in use into a [`BTreeMap`].
+	pub fn into_btree_map(self) -> BTreeMap<CurrencyCode, RATE> {
+		// in insertion order, so that superseded rates are overwritten.
+		self.into_iter().rev().collect()
+	}
+
+	/// Clones the rates in use into a [`HashMap`].
+	pub fn to_hash_map(&self) -> HashMap<CurrencyCode, RATE> where RATE: Clone {
+		self.entries().map(|(currency, rate)| (currency, rate.clone())).collect()
+	}
+
+	/// Hashes the rates in use regardless of their order, e.g. to detect that a fetch changed nothing.
+	///
+	/// Rates are hashed by their [`Display`](fmt::Display) form, so that floats can be fingerprinted too. The fingerprint
+	/// is only stable within a process, and may change across versions of this crate or of Rust.
+	pub fn fingerprint(&self) -> u64 where RATE: fmt::Display {
+		let mut hasher = DefaultHasher::new();
+		for (currency, rate) in self.alphabetical_entries() {
+			currency.hash(&mut hasher);
+			rate.to_string().hash(&mut hasher);
+		}
+		hasher.finish()
+	}
+
+	/// Compares with other [`Rates`] including their order (and their superseded rates), unlike [`PartialEq`].
+	pub fn eq_ordered<const M: usize>(&self, other: &Rates<RATE, M>) -> bool where RATE: PartialEq {
+		self.currencies() == other.currencies() && self.rates() == other.rates()
+	}
+
+	/// Converts an amount to every currency in use, like [`RateTable::convert`], including `from` itself if
+	/// `include_from`.
+	///
+	/// Returns [`None`] if the `from` currency is missing.
+	pub fn convert_all<'a>(&'a self, amount: &'a RATE, from: CurrencyCode, include_from: bool) -> Option<impl Iterator<Item = (CurrencyCode, RATE)> + 'a>
+	where for<'x> &'x RATE: Div<&'x RATE, Output = RATE>, for<'x> &'x RATE: Mul<RATE, Output = RATE> {
+		let from_value = self.get(from)?;
+		Some(self.entries()
+			.filter(move |&(currency, _)| include_from || currency != from)
+			.map(move |(currency, to_value)| (currency, amount * (to_value / from_value))))
+	}
+
+	/// Computes the conversion factor of every pair of currencies, as `(from, to, factor)`.
+	///
+	/// Pairs are ordered by `from` then by `to`, each in insertion order.
+	pub fn cross_matrix(&self) -> Vec<(CurrencyCode, CurrencyCode, RATE)> where for<'x> &'x RATE: Div<&'x RATE, Output = RATE> {
+		self.entries()
+			.flat_map(|(from, from_value)| self.entries().map(move |(to, to_value)| (from, to, to_value / from_value)))
+			.collect()
+	}
+
+	/// Compares the rates in use with a `previous` snapshot, yielding the currencies whose rates changed.
+	///
+	/// Yields the changed and added currencies in insertion order, then the removed currencies.
+	pub fn diff<'a, const M: usize>(&'a self, previous: &'a Rates<RATE, M>) -> impl Iterator<Item = RateChange<'a, RATE>> where RATE: PartialEq {
+		let current = self.entries().filter_map(|(currency, new)| match previous.get(currency) {
+			None => Some(RateChange::Added { currency, rate: new }),
+			Some(old) if old != new => Some(RateChange::Changed { currency, old, new }),
+			Some(_) => None,
+		});
+		let removed = previous.entries()
+			.filter(|&(currency, _)| !self.contains(currency))
+			.map(|(currency, rate)| RateChange::Removed { currency, rate });
+		current.chain(removed)
+	}
+
+	/// Makes the rates relative to the given currency, so that its rate becomes exactly one.
+	///
+	/// Fails, leaving the rates untouched, if the new base currency is missing or its rate is zero.
+	pub fn rebase(&mut self, new_base: CurrencyCode) -> Result<(), RebaseError>
+	where RATE: From<u8> + PartialEq, for<'x> &'x RATE: Div<&'x RATE, Output = RATE> {
+		let i = self.index_of(new_base).ok_or(RebaseError::Missing(new_base))?;
+		let (before, rest) = self.rates_mut().split_at_mut(i);
+		let (base, after) = rest.split_first_mut().unwrap();
+		if *base == RATE::from(0) { return Err(RebaseError::Zero(new_base)); }
+		for rate in before.iter_mut().chain(after) { *rate = &*rate / &*base; }
+		*base = RATE::from(1);
+		Ok(())
+	}
+
+	/// Replaces every rate with its reciprocal, i.e. the value of one unit of the currency in the base currency.
+	///
+	/// Fails, leaving the rates untouched, if a rate is zero.
+	pub fn invert(&mut self) -> Result<(), ZeroRateError>
+	where RATE: From<u8> + PartialEq, for<'x> &'x RATE: Div<&'x RATE, Output = RATE> {
+		self.check_nonzero()?;
+		let one = RATE::from(1);
+		for rate in self.rates_mut() { *rate = &one / &*rate; }
+		Ok(())
+	}
+
+	/// Gets a copy of the rates with every rate replaced by its reciprocal, see [`Rates::invert`].
+	pub fn inverted(&self) -> Result<Self, ZeroRateError>
+	where RATE: From<u8> + PartialEq, for<'x> &'x RATE: Div<&'x RATE, Output = RATE> {
+		self.check_nonzero()?;
+		let one = RATE::from(1);
+		Ok(self.map_rates_ref(|rate| &one / rate))
+	}
+
+	/// Multiplies every rate by the given factor, e.g. `1.02` for a 2% margin over the mid-market rates.
+	pub fn apply_margin(&mut self, factor: RATE) where for<'x> &'x RATE: Mul<&'x RATE, Output = RATE> {
+		for rate in self.rates_mut() { *rate = &*rate * &factor; }
+	}
+
+	/// Multiplies every rate by its currency's factor, like [`Rates::apply_margin`], e.g. to mark up exotic currencies
+	/// more.
+	pub fn apply_margin_with(&mut self, mut factor: impl FnMut(CurrencyCode) -> RATE)
+	where for<'x> &'x RATE: Mul<&'x RATE, Output = RATE> {
+		let (currencies, rates) = self.slices_mut();
+		for (&currency, rate) in currencies.iter().zip(rates) { *rate = &*rate * &factor(currency); }
+	}
+
+	/// Gets a copy of the rates with the margin applied, see [`Rates::apply_margin`], leaving these rates intact.
+	pub fn with_margin(&self, factor: RATE) -> Self where for<'x> &'x RATE: Mul<&'x RATE, Output = RATE> {
+		self.map_rates_ref(|rate| rate * &factor)
+	}
+
+	/// Rounds every rate to the given number of decimal places, [half up](RoundingMode::HalfUp).
+	///
+	/// Subsequent conversions use the rounded rates, making them deterministic for display. Floats are rounded
+	/// best-effort, see [`RoundableRate`].
+	pub fn round_all(&mut self, decimal_places: u32) where RATE: RoundableRate {
+		for rate in self.rates_mut() { *rate = rate.round_to(decimal_places, RoundingMode::HalfUp); }
+	}
+
+	/// Fails with the first currency whose rate is zero.
+	fn check_nonzero(&self) -> Result<(), ZeroRateError> where RATE: From<u8> + PartialEq {
+		let zero = RATE::from(0);
+		match self.currencies().iter().zip(self.rates()).find(|&(_, rate)| *rate == zero) {
+			Some((&currency, _)) => Err(ZeroRateError(currency)),
+			None => Ok(()),
+		}
+	}
+
+	/// Merges the rates of another [`Rates`], resolving currencies present in both per the given policy.
+	///
+	/// Currencies new to `self` are pushed until full, see the returned [`MergeSummary`] for how many didn't fit.
+	///
+	/// Useful for combining rates fetched separately, e.g. per base currency.
+	pub fn merge<const M: usize>(&mut self, other: &Rates<RATE, M>, policy: MergePolicy) -> MergeSummary where RATE: Clone {
+		self.merge_by(other, |_, existing, incoming| match policy {
+			MergePolicy::KeepSelf => {},
+			MergePolicy::KeepOther => existing.clone_from(incoming),
+		})
+	}
+
+	/// Merges the rates of another [`Rates`], resolving currencies present in both with the given function of the
+	/// currency, its existing rate and its incoming rate.
+	///
+	/// Currencies new to `self` are pushed until full, see the returned [`MergeSummary`] for how many didn't fit.
+	pub fn merge_with<const M: usize>(&mut self, other: &Rates<RATE, M>, mut resolve: impl FnMut(CurrencyCode, &RATE, &RATE) -> RATE) -> MergeSummary where RATE: Clone {
+		self.merge_by(other, |currency, existing, incoming| *existing = resolve(currency, existing, incoming))
+	}
+
+	fn merge_by<const M: usize>(&mut self, other: &Rates<RATE, M>, mut update: impl FnMut(CurrencyCode, &mut RATE, &RATE)) -> MergeSummary where RATE: Clone {
+		let mut summary = MergeSummary::default();
+		for (currency, incoming) in other.entries() {
+			if let Some(existing) = self.get_mut(currency) {
+				update(currency, existing, incoming);
+				summary.shared += 1;
+			} else if self.push(currency, incoming.clone()) {
+				summary.added += 1;
+			} else {
+				summary.dropped += 1;
+			}
+		}
+		summary
+	}
+
+	/// Collects rates from an iterator, failing with the first rate that doesn't fit.
+	pub fn try_from_iter(iter: impl IntoIterator<Item = (CurrencyCode, RATE)>) -> Result<Self, CapacityError<RATE>> {
+		let mut rates = Self::new();
+		rates.try_extend(iter)?;
+		Ok(rates)
+	}
+
+	/// Appends the given iterator rates, failing on the first one that doesn't fit.
+	///
+	/// The rates before it remain appended, see [`Rates::extend_capped`] to append until full instead.
+	pub fn try_extend(&mut self, iter: impl IntoIterator<Item = (CurrencyCode, RATE)>) -> Result<(), CapacityError<RATE>> {
+		for (currency, rate) in iter { self.try_push(currency, rate)?; }
+		Ok(())
 	}
 
 	/// Appends the given iterator rates, until full.
 	///
-	/// Returns whether all values were appended.
-	pub fn extend_capped(&mut self, iter: impl IntoIterator<Item = (CurrencyCode, RATE)>) -> bool {
+	/// Returns how many rates were appended.
+	pub fn extend_capped(&mut self, iter: impl IntoIterator<Item = (CurrencyCode, RATE)>) -> usize {
+		let len = self.len;
 		for (currency, rate) in iter {
-			if !self.push(currency, rate) { return false }
+			if self.try_push(currency, rate).is_err() { break }
 		}
-		true
+		self.len - len
+	}
+
+	/// Sorts the rates by currency, which makes lookups a binary search rather than a linear scan.
+	///
+	/// The sort is stable, so the latest pushed rate of a currency remains the one in use. Pushing a
+	/// currency that is ordered before the last one unsorts the rates.
+	pub fn sort(&mut self) {
+		if self.sorted { return; }
+		let (currencies, rates) = self.slices_mut();
+		sort(currencies, rates);
+		self.sorted = true;
 	}
 
+	/// Gets whether the rates are sorted by currency, see [`Rates::sort`].
+	#[inline] pub const fn is_sorted(&self) -> bool { self.sorted }
+
+	/// Gets the index of the given currency's rate in [`Rates::currencies`] and [`Rates::rates`], if exists.
+	#[inline] pub fn index_of(&self, currency: CurrencyCode) -> Option<usize> { position(self.currencies(), self.sorted, currency) }
+
+	/// Gets whether the given currency has a rate, without requiring [`RateTable`] in scope.
+	#[inline] pub fn contains(&self, currency: CurrencyCode) -> bool { self.index_of(currency).is_some() }
+
 	/// Gets the rate for the given currency, if exists.
 	pub fn get(&self, currency: CurrencyCode) -> Option<&RATE> {
-		self.iter()
-			.find(|&(c,_)| c == currency)
-			.map(|(_,r)| r)
+		self.index_of(currency).map(|i| &self.rates()[i])
+	}
+
+	/// Gets the rate for the given currency mutably, if exists.
+	pub fn get_mut(&mut self, currency: CurrencyCode) -> Option<&mut RATE> {
+		let i = self.index_of(currency)?;
+		Some(&mut self.rates_mut()[i])
 	}
 
-	/// Covnerts an amount between currencies.
+	/// Removes the rate at the given index, shifting the following rates.
 	///
-	/// Returns [`None`] if either the `from` or `to` currencies are missing.
-	pub fn convert(&self, amount: &RATE, from: CurrencyCode, to: CurrencyCode) -> Option<RATE>
-	where for<'x> &'x RATE: Div<&'x RATE, Output = RATE>, for<'x> &'x RATE: Mul<RATE, Output = RATE> {
-		let from_value = self.get(from)?;
-		let to_value = self.get(to)?;
-		Some(amount * (to_value / from_value))
+	/// # Safety
+	/// Ensure `i` < [`Rates::len`].
+	unsafe fn remove_at(&mut self, i: usize) -> RATE {
+		let tail = self.len - i - 1;
+		let rate = self.rate.get_unchecked(i).assume_init_read();
+		ptr::copy(self.currency.as_ptr().add(i + 1), self.currency.as_mut_ptr().add(i), tail);
+		ptr::copy(self.rate.as_ptr().add(i + 1), self.rate.as_mut_ptr().add(i), tail);
+		self.len -= 1;
+		rate
+	}
+
+	/// Removes the rate at the given index, replacing it with the last rate.
+	///
+	/// Shifts the following rates instead, like [`Rates::remove_at`], if the last currency has a superseded rate after
+	/// `i`, since moving the last rate before it would bring it back in use.
+	///
+	/// # Safety
+	/// Ensure `i` < [`Rates::len`].
+	unsafe fn swap_remove_at(&mut self, i: usize) -> RATE {
+		let last = self.len - 1;
+		if i != last && self.currencies()[i + 1..last].contains(&self.currencies()[last]) {
+			return self.remove_at(i);
+		}
+		let rate = self.rate.get_unchecked(i).assume_init_read();
+		if i != last {
+			*self.currency.get_unchecked_mut(i) = *self.currency.get_unchecked(last);
+			ptr::copy_nonoverlapping(self.rate.as_ptr().add(last), self.rate.as_mut_ptr().add(i), 1);
+			self.sorted = false;
+		}
+		self.len -= 1;
+		rate
+	}
+
+	/// Retains only the rates for which the predicate returns `true`, preserving their order.
+	pub fn retain(&mut self, mut f: impl FnMut(CurrencyCode, &RATE) -> bool) {
+		let len = self.len;
+		// Should f or a drop panic, the remaining rates leak rather than get dropped twice.
+		self.len = 0;
+		let mut kept = 0;
+		for i in 0..len {
+			unsafe {
+				// SAFETY: i < len, and kept <= i so it's initialized or moved out of.
+				let currency = self.currency.get_unchecked(i).assume_init();
+				if f(currency, self.rate.get_unchecked(i).assume_init_ref()) {
+					if kept != i {
+						*self.currency.get_unchecked_mut(kept) = MaybeUninit::new(currency);
+						ptr::copy_nonoverlapping(self.rate.as_ptr().add(i), self.rate.as_mut_ptr().add(kept), 1);
+					}
+					kept += 1;
+				} else {
+					self.rate.get_unchecked_mut(i).assume_init_drop();
+				}
+			}
+		}
+		self.len = kept;
+	}
+
+	/// Removes a currency, preserving the order of the other rates.
+	///
+	/// Returns the currency's rate, if it existed.
+	pub fn remove(&mut self, currency: CurrencyCode) -> Option<RATE> {
+		let rate = unsafe {
+			// SAFETY: index_of is in bounds.
+			self.remove_at(self.index_of(currency)?)
+		};
+		while let Some(i) = self.index_of(currency) {
+			// SAFETY: index_of is in bounds.
+			drop(unsafe { self.remove_at(i) });
+		}
+		Some(rate)
+	}
+
+	/// Removes a currency in constant time (per occurrence), replacing it with the last rate.
+	///
+	/// Unsorts the rates. Takes linear time instead when the last currency has superseded rates, to keep its latest rate
+	/// in use. Returns the currency's rate, if it existed.
+	pub fn swap_remove(&mut self, currency: CurrencyCode) -> Option<RATE> {
+		let rate = unsafe {
+			// SAFETY: index_of is in bounds.
+			self.swap_remove_at(self.index_of(currency)?)
+		};
+		while let Some(i) = self.index_of(currency) {
+			// SAFETY: index_of is in bounds.
+			drop(unsafe { self.swap_remove_at(i) });
+		}
+		Some(rate)
 	}
 }
-impl<const N: usize, RATE> Default for Rates<RATE, N> { #[inline] fn default() -> Self { Self::new() } }
 
-impl<const N: usize, RATE: fmt::Debug> fmt::Debug for Rates<RATE, N> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		let mut m = f.debug_map();
-		for (currency, rate) in self.iter() {
-			m.entry(&currency, rate);
+/// Gets the rate of a currency.
+///
+/// # Panics
+/// Panics if the currency is missing, see [`Rates::get`] for a non-panicking alternative.
+impl<const N: usize, RATE> Index<CurrencyCode> for Rates<RATE, N> {
+	type Output = RATE;
+	#[inline] #[track_caller] fn index(&self, currency: CurrencyCode) -> &RATE {
+		match self.get(currency) {
+			Some(rate) => rate,
+			None => panic!("missing {currency} rate"),
 		}
-		m.finish()
 	}
 }
 
-#[cfg(test)]
-mod test {
-	use super::*;
+/// Gets the rate of a currency by its code.
+///
+/// # Panics
+/// Panics if the currency code is invalid or missing, see [`RateTable::get_by_str`] for a non-panicking alternative.
+impl<const N: usize, RATE> Index<&str> for Rates<RATE, N> {
+	type Output = RATE;
+	#[inline] #[track_caller] fn index(&self, currency: &str) -> &RATE {
+		match currency.parse::<CurrencyCode>() {
+			Ok(currency) => &self[currency],
+			Err(e) => panic!("invalid currency code {currency:?}: {e}"),
+		}
+	}
+}
 
-	#[test]
-	fn test_convert() {
-		use crate::currency::*;
-		let mut rates = Rates::<f64, 3>::new();
-		rates.push(USD, 1.0);
-		rates.push(EUR, 0.9);
-		rates.push(ILS, 3.1);
-		assert_eq!(rates.convert(&1234.0, USD, USD), Some(1234.));
-		assert_eq!(rates.convert(&1234.0, EUR, EUR), Some(1234.));
-		assert_eq!(rates.convert(&1234.0, ILS, ILS), Some(1234.));
-		assert_eq!(rates.convert(&1.0, ILS, EUR), Some(1. / 3.1 * 0.9));
-		assert_eq!(rates.convert(&1.0, EUR, ILS), Some(1. / 0.9 * 3.1));
+/// Scales every rate by a factor, see [`Rates::apply_margin`].
+///
+/// # Panics
+/// If the rates' multiplication does, e.g. `Decimal` on overflow. Float rates become infinite or NaN instead.
+impl<const N: usize, RATE> MulAssign<RATE> for Rates<RATE, N> where for<'x> &'x RATE: Mul<&'x RATE, Output = RATE> {
+	#[inline] fn mul_assign(&mut self, rhs: RATE) { self.apply_margin(rhs) }
+}
+
+/// Scales every rate by a factor, see [`MulAssign`](#impl-MulAssign<RATE>-for-Rates<RATE,+N>).
+impl<const N: usize, RATE> Mul<RATE> for Rates<RATE, N> where for<'x> &'x RATE: Mul<&'x RATE, Output = RATE> {
+	type Output = Self;
+	#[inline] fn mul(mut self, rhs: RATE) -> Self { self *= rhs; self }
+}
+
+/// Scales a copy of every rate by a factor, see [`Rates::with_margin`].
+impl<const N: usize, RATE> Mul<RATE> for &Rates<RATE, N> where for<'x> &'x RATE: Mul<&'x RATE, Output = RATE> {
+	type Output = Rates<RATE, N>;
+	#[inline] fn mul(self, rhs: RATE) -> Rates<RATE, N> { self.with_margin(rhs) }
+}
+
+/// Yields the currency rates in the same order as [`Rates::iter`].
+impl<const N: usize, RATE> IntoIterator for Rates<RATE, N> {
+	type Item = (CurrencyCode, RATE);
+	type IntoIter = RatesIntoIter<RATE, N>;
+	#[inline] fn into_iter(self) -> Self::IntoIter {
+		RatesIntoIter {
+			start: 0,
+			end: self.len,
+			rates: mem::ManuallyDrop::new(self),
+		}
 	}
+}
 
-	#[test]
-	fn test_duplicates() {
-		use crate::currency::*;
-		let mut rates = Rates::<f64, 10>::new();
-		rates.push(USD, 1.0);
-		rates.push(EUR, 2.0);
-		assert_eq!(rates.get(USD).unwrap(), &1.0);
-		rates.push(USD, 3.0);
-		assert_eq!(rates.get(USD).unwrap(), &3.0);
+/// An owning iterator over [`Rates`].
+pub struct RatesIntoIter<RATE, const N: usize> {
+	/// The rates, whose initialized rates are start..end (rather than its len).
+	rates: mem::ManuallyDrop<Rates<RATE, N>>,
+	start: usize,
+	end: usize,
+}
+
+impl<const N: usize, RATE> RatesIntoIter<RATE, N> {
+	/// Moves the rate at the given index out.
+	///
+	/// # Safety
+	/// Ensure `i` is within start..end, and is then excluded from it.
+	unsafe fn take(&mut self, i: usize) -> (CurrencyCode, RATE) {
+		(
+			self.rates.currency.get_unchecked(i).assume_init(),
+			self.rates.rate.get_unchecked(i).assume_init_read(),
+		)
+	}
+}
+
+impl<const N: usize, RATE> Iterator for RatesIntoIter<RATE, N> {
+	type Item = (CurrencyCode, RATE);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.start == self.end { return None; }
+		self.end -= 1;
+		// SAFETY: end was just excluded.
+		Some(unsafe { self.take(self.end) })
+	}
+
+	#[inline] fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end - self.start;
+		(len, Some(len))
+	}
+}
+
+impl<const N: usize, RATE> DoubleEndedIterator for RatesIntoIter<RATE, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.start == self.end { return None; }
+		self.start += 1;
+		// SAFETY: start - 1 was just excluded.
+		Some(unsafe { self.take(self.start - 1) })
+	}
+}
+
+impl<const N: usize, RATE> ExactSizeIterator for RatesIntoIter<RATE, N> {}
+
+impl<const N: usize, RATE> Drop for RatesIntoIter<RATE, N> {
+	fn drop(&mut self) {
+		unsafe {
+			// SAFETY: start..end are initialized, and were not moved out.
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+				self.rates.rate.as_mut_ptr().cast::<RATE>().add(self.start),
+				self.end - self.start,
+			));
+		}
+	}
+}
+
+/// An error for a rate that didn't fit in a full [`Rates`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("no capacity for the {currency} rate")]
+pub struct CapacityError<RATE> {
+	/// The rejected currency.
+	pub currency: CurrencyCode,
+	/// The rejected rate.
+	pub rate: RATE,
+}
+
+impl<const N: usize, RATE> TryFrom<HashMap<CurrencyCode, RATE>> for Rates<RATE, N> {
+	type Error = CapacityError<RATE>;
+	#[inline] fn try_from(rates: HashMap<CurrencyCode, RATE>) -> Result<Self, Self::Error> { Self::try_from_iter(rates) }
+}
+
+impl<const N: usize, RATE> TryFrom<BTreeMap<CurrencyCode, RATE>> for Rates<RATE, N> {
+	type Error = CapacityError<RATE>;
+	#[inline] fn try_from(rates: BTreeMap<CurrencyCode, RATE>) -> Result<Self, Self::Error> { Self::try_from_iter(rates) }
+}
+
+/// A change of a currency rate between snapshots, see [`Rates::diff`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum RateChange<'a, RATE> {
+	/// The currency is new.
+	Added {
+		/// The currency.
+		currency: CurrencyCode,
+		/// The currency's rate.
+		rate: &'a RATE,
+	},
+	/// The currency is gone.
+	Removed {
+		/// The currency.
+		currency: CurrencyCode,
+		/// The currency's previous rate.
+		rate: &'a RATE,
+	},
+	/// The currency's rate changed.
+	Changed {
+		/// The currency.
+		currency: CurrencyCode,
+		/// The currency's previous rate.
+		old: &'a RATE,
+		/// The currency's current rate.
+		new: &'a RATE,
+	},
+}
+
+impl<RATE> RateChange<'_, RATE> {
+	/// Gets the changed currency.
+	pub const fn currency(&self) -> CurrencyCode {
+		match *self {
+			Self::Added { currency, .. } | Self::Removed { currency, .. } | Self::Changed { currency, .. } => currency,
+		}
+	}
+
+	/// Gets the relative change of a [changed](RateChange::Changed) rate, i.e. `(new - old) / old`.
+	pub fn relative_change(&self) -> Option<RATE>
+	where for<'x> &'x RATE: Sub<&'x RATE, Output = RATE>, for<'x> &'x RATE: Div<&'x RATE, Output = RATE> {
+		match *self {
+			Self::Changed { old, new, .. } => Some(&(new - old) / old),
+			Self::Added { .. } | Self::Removed { .. } => None,
+		}
+	}
+}
+
+/// An error rebasing [`Rates`], see [`Rates::rebase`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RebaseError {
+	/// The new base currency has no rate.
+	#[error("missing {0} rate")]
+	Missing(CurrencyCode),
+	/// The new base currency's rate is zero.
+	#[error("zero {0} rate")]
+	Zero(CurrencyCode),
+}
+
+/// An error for a currency whose rate is zero, where it can't be inverted, see [`Rates::invert`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("zero {0} rate")]
+pub struct ZeroRateError(pub CurrencyCode);
+
+/// An error selecting a subset of [`Rates`], see [`Rates::subset`].
+#[derive(Debug, Hash, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SubsetError {
+	/// Some of the currencies have no rate, each listed once in the requested order.
+	#[error("missing the rates of {} currencies", .0.len())]
+	Missing(Vec<CurrencyCode>),
+	/// There are more currencies than fit in the subset.
+	#[error("more than {capacity} currencies")]
+	Capacity {
+		/// The capacity of the subset.
+		capacity: usize,
+	},
+}
+
+/// Pushes the rates.
+///
+/// # Panics
+/// Panics if the rates don't fit, see [`Rates::try_extend`] and [`Rates::extend_capped`] for non-panicking
+/// alternatives.
+impl<const N: usize, RATE> Extend<(CurrencyCode, RATE)> for Rates<RATE, N> {
+	fn extend<T: IntoIterator<Item = (CurrencyCode, RATE)>>(&mut self, iter: T) {
+		for (currency, rate) in iter {
+			assert!(self.push(currency, rate), "extended Rates beyond its capacity ({N})");
+		}
+	}
+}
+
+impl<const N: usize, RATE: Clone> TryFrom<&[(CurrencyCode, RATE)]> for Rates<RATE, N> {
+	type Error = CapacityError<RATE>;
+	#[inline] fn try_from(rates: &[(CurrencyCode, RATE)]) -> Result<Self, Self::Error> {
+		Self::try_from_iter(rates.iter().cloned())
+	}
+}
+
+/// How [`Rates::merge`] resolves a currency present in both [`Rates`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+pub enum MergePolicy {
+	/// Keep the existing rate.
+	KeepSelf,
+	/// Take the incoming rate.
+	KeepOther,
+}
+
+/// The outcome of a [`Rates::merge`], counting the incoming currencies.
+#[derive(Debug, Default, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct MergeSummary {
+	/// Currencies new to the [`Rates`], that were pushed.
+	pub added: usize,
+	/// Currencies already in the [`Rates`], that were resolved per the merge policy or function.
+	pub shared: usize,
+	/// Currencies new to the [`Rates`], that didn't fit.
+	pub dropped: usize,
+}
+
+impl<const N: usize, RATE> RateTable for Rates<RATE, N> {
+	type Rate = RATE;
+	#[inline] fn get(&self, currency: CurrencyCode) -> Option<&RATE> { Rates::get(self, currency) }
+	#[inline] fn contains(&self, currency: CurrencyCode) -> bool { Rates::contains(self, currency) }
+}
+
+/// Stable-sorts parallel currency and rate slices by currency.
+pub(crate) fn sort<RATE>(currencies: &mut [CurrencyCode], rates: &mut [RATE]) {
+	for i in 1..currencies.len() {
+		let mut j = i;
+		while j > 0 && currencies[j - 1] > currencies[j] {
+			currencies.swap(j - 1, j);
+			rates.swap(j - 1, j);
+			j -= 1;
+		}
+	}
+}
+
+/// Gets the index of the last occurrence of the currency, using binary search if the currencies are `sorted`.
+pub(crate) fn position(currencies: &[CurrencyCode], sorted: bool, currency: CurrencyCode) -> Option<usize> {
+	if sorted {
+		let end = currencies.partition_point(|&c| c <= currency);
+		(end > 0 && currencies[end - 1] == currency).then(|| end - 1)
+	} else {
+		currencies.iter().rposition(|&c| c == currency)
+	}
+}
+impl<const N: usize, RATE> Default for Rates<RATE, N> { #[inline] fn default() -> Self { Self::new() } }
+
+impl<const N: usize, RATE: Clone> Clone for Rates<RATE, N> {
+	fn clone(&self) -> Self {
+		let mut rates = Self::new();
+		for (&currency, rate) in self.currencies().iter().zip(self.rates()) {
+			unsafe {
+				// SAFETY: self has no more than N rates.
+				rates.push_unchecked(currency, rate.clone());
+			}
+		}
+		rates.updated_at = self.updated_at;
+		rates
+	}
+
+	fn clone_from(&mut self, source: &Self) {
+		let (len, source_len) = (self.len, source.len);
+		let common = len.min(source_len);
+		unsafe {
+			// SAFETY: common..len are initialized, and are excluded by the new len before dropping.
+			self.len = common;
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+				self.rate.as_mut_ptr().cast::<RATE>().add(common),
+				len - common,
+			));
+		}
+		let (currencies, rates) = self.slices_mut();
+		currencies.copy_from_slice(&source.currencies()[..common]);
+		for (rate, source_rate) in rates.iter_mut().zip(source.rates()) { rate.clone_from(source_rate); }
+		for (&currency, rate) in source.currencies()[common..].iter().zip(&source.rates()[common..]) {
+			unsafe {
+				// SAFETY: source has no more than N rates.
+				self.push_unchecked(currency, rate.clone());
+			}
+		}
+		self.sorted = source.sorted;
+		self.updated_at = source.updated_at;
+	}
+}
+
+/// Compares the rates in use regardless of their order, see [`Rates::eq_ordered`] for an order-sensitive comparison.
+impl<const N: usize, const M: usize, RATE: PartialEq> PartialEq<Rates<RATE, M>> for Rates<RATE, N> {
+	fn eq(&self, other: &Rates<RATE, M>) -> bool {
+		self.entries().count() == other.entries().count()
+			&& self.entries().all(|(currency, rate)| other.get(currency) == Some(rate))
+	}
+}
+
+impl<const N: usize, RATE: Eq> Eq for Rates<RATE, N> {}
+
+impl<const N: usize, RATE> Drop for Rates<RATE, N> {
+	fn drop(&mut self) {
+		unsafe {
+			// SAFETY: the first self.len rates are initialized, and dropped only here.
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+				self.rate.as_mut_ptr().cast::<RATE>(),
+				self.len,
+			));
+		}
+	}
+}
+
+/// Serializes as a map from currency to rate, like the API's `data` field (without the nesting).
+///
+/// The currencies are serialized in alphabetical order, regardless of the order of the [`Rates`], so that the output is
+/// stable.
+impl<const N: usize, RATE: Serialize> Serialize for Rates<RATE, N> {
+	#[inline] fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { self.serialize_map(serializer, |currency| currency) }
+}
+
+impl<const N: usize, RATE: Serialize> Rates<RATE, N> {
+	/// Serializes as a map from currency to rate, see the [`Serialize`] implementation, with the currencies mapped by
+	/// `key`.
+	fn serialize_map<S: Serializer, K: Serialize>(&self, serializer: S, key: impl Fn(CurrencyCode) -> K) -> Result<S::Ok, S::Error> {
+		let entries = self.alphabetical_entries();
+		let mut map = serializer.serialize_map(Some(entries.len()))?;
+		for (currency, rate) in entries { map.serialize_entry(&key(currency), rate)?; }
+		map.end()
+	}
+}
+
+/// Deserializes from a map from currency to rate, see the [`Serialize`] implementation.
+///
+/// In human-readable formats, the rates may also be strings, parsed with [`FromScientific`].
+///
+/// Fails if there are more than `N` currencies, rather than silently dropping rates.
+impl<'de, const N: usize, RATE: Deserialize<'de> + FromScientific<Error: fmt::Display>> Deserialize<'de> for Rates<RATE, N> {
+	#[inline] fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_map(RatesVisitor::<RATE, CurrencyCode, N>(PhantomData))
+	}
+}
+
+/// Deserializes [`Rates`] from a map from currency, deserialized as `KEY`, to rate.
+struct RatesVisitor<RATE, KEY, const N: usize>(PhantomData<(RATE, KEY)>);
+
+impl<'de, const N: usize, RATE: Deserialize<'de> + FromScientific<Error: fmt::Display>, KEY: Deserialize<'de> + Into<CurrencyCode>> de::Visitor<'de> for RatesVisitor<RATE, KEY, N> {
+	type Value = Rates<RATE, N>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(formatter, "a map of up to {N} currency rates")
+	}
+
+	fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+		let mut rates = Rates::new();
+		while let Some((currency, ScientificRate(rate))) = map.next_entry::<KEY, _>()? {
+			if rates.insert_or_update(currency.into(), rate).is_err() {
+				return Err(de::Error::invalid_length(N + 1, &self));
+			}
+		}
+		rates.sort();
+		Ok(rates)
+	}
+}
+
+/// Compact binary snapshots with [postcard](https://docs.rs/postcard).
+///
+/// The format is postcard's encoding of the [`Serialize`] implementation: the count of currencies as a varint, followed
+/// by each currency's 8-byte code (in ASCII, padded with zeroes) and its rate, in alphabetical order. Postcard's wire
+/// format is stable across its 1.x versions, and so is this one, for a given `RATE` type.
+#[cfg(feature = "binary-serde")]
+impl<const N: usize, RATE> Rates<RATE, N> {
+	/// Serializes the rates to postcard bytes.
+	pub fn to_postcard(&self) -> Result<Vec<u8>, postcard::Error> where RATE: Serialize {
+		postcard::to_allocvec(&BinaryRates(self))
+	}
+
+	/// Deserializes rates from postcard bytes, see [`Rates::to_postcard`].
+	pub fn from_postcard<'de>(bytes: &'de [u8]) -> Result<Self, postcard::Error>
+	where RATE: Deserialize<'de> + FromScientific<Error: fmt::Display> {
+		postcard::from_bytes::<BinaryRates<Self>>(bytes).map(|BinaryRates(rates)| rates)
+	}
+}
+
+/// [`Rates`] (or a reference to them) serialized with their currencies as [`BinaryCurrency`], see
+/// [`Rates::to_postcard`].
+#[cfg(feature = "binary-serde")]
+struct BinaryRates<R>(R);
+
+#[cfg(feature = "binary-serde")]
+impl<const N: usize, RATE: Serialize> Serialize for BinaryRates<&Rates<RATE, N>> {
+	#[inline] fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { self.0.serialize_map(serializer, BinaryCurrency) }
+}
+
+#[cfg(feature = "binary-serde")]
+impl<'de, const N: usize, RATE: Deserialize<'de> + FromScientific<Error: fmt::Display>> Deserialize<'de> for BinaryRates<Rates<RATE, N>> {
+	#[inline] fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_map(RatesVisitor::<RATE, BinaryCurrency, N>(PhantomData)).map(Self)
+	}
+}
+
+/// A currency serialized as its 8-byte representation: the code in ASCII followed by zeroes.
+#[cfg(feature = "binary-serde")]
+struct BinaryCurrency(CurrencyCode);
+
+#[cfg(feature = "binary-serde")]
+impl From<BinaryCurrency> for CurrencyCode {
+	#[inline] fn from(BinaryCurrency(currency): BinaryCurrency) -> Self { currency }
+}
+
+#[cfg(feature = "binary-serde")]
+impl Serialize for BinaryCurrency {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let code = AsRef::<[u8]>::as_ref(&self.0);
+		let mut bytes = [0u8; mem::size_of::<CurrencyCode>()];
+		bytes[..code.len()].copy_from_slice(code);
+		bytes.serialize(serializer)
+	}
+}
+
+/// Accepts only uppercase codes, unlike [`CurrencyCode::parse`], since [`Serialize`] never writes lowercase ones.
+#[cfg(feature = "binary-serde")]
+impl<'de> Deserialize<'de> for BinaryCurrency {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes = <[u8; mem::size_of::<CurrencyCode>()]>::deserialize(deserializer)?;
+		let len = bytes.iter().position(|&c| c == 0).unwrap_or(bytes.len());
+		if let Some(&c) = bytes[..len].iter().find(|c| !c.is_ascii_uppercase()) {
+			return Err(de::Error::custom(crate::CurrencyError::InvalidCharacter(c)));
+		}
+		if bytes[len..].iter().any(|&c| c != 0) {
+			return Err(de::Error::custom("the currency code has non-zero padding"));
+		}
+		CurrencyCode::parse(&bytes[..len]).map(Self).map_err(de::Error::custom)
+	}
+}
+
+/// A rate deserialized from either a number or a numeric string.
+struct ScientificRate<RATE>(RATE);
+
+impl<'de, RATE: Deserialize<'de> + FromScientific<Error: fmt::Display>> Deserialize<'de> for ScientificRate<RATE> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if !deserializer.is_human_readable() { return RATE::deserialize(deserializer).map(Self) }
+		// Numbers are parsed from their text rather than through an f64, which with serde_json's `arbitrary_precision`
+		// (enabled by the decimal features) keeps all their digits.
+		let rate = match serde_json::Value::deserialize(deserializer)? {
+			serde_json::Value::Number(number) => number.to_string(),
+			serde_json::Value::String(string) => string,
+			value => {
+				let unexpected = match value {
+					serde_json::Value::Null => de::Unexpected::Unit,
+					serde_json::Value::Bool(value) => de::Unexpected::Bool(value),
+					serde_json::Value::Array(_) => de::Unexpected::Seq,
+					_ => de::Unexpected::Map,
+				};
+				return Err(de::Error::invalid_type(unexpected, &"a number or a numeric string"));
+			},
+		};
+		RATE::parse_scientific(&rate).map(Self).map_err(|e| de::Error::custom(format_args!("invalid rate {rate:?}: {e}")))
+	}
+}
+
+impl<const N: usize, RATE: fmt::Debug> fmt::Debug for Rates<RATE, N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut m = f.debug_map();
+		for (currency, rate) in self.iter() {
+			m.entry(&currency, rate);
+		}
+		m.finish()
+	}
+}
+
+/// Renders the rates in use as an aligned table of currency and rate lines, in insertion order.
+///
+/// The alternate flag (`{:#}`) orders the lines alphabetically by currency instead, and the precision (e.g. `{:.4}`)
+/// applies to the rates.
+impl<const N: usize, RATE: fmt::Display> fmt::Display for Rates<RATE, N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let entries = if f.alternate() { self.alphabetical_entries() } else { self.entries().collect() };
+		let lines = entries.into_iter().map(|(currency, rate)| (currency, match f.precision() {
+			Some(precision) => format!("{rate:.precision$}"),
+			None => rate.to_string(),
+		})).collect::<Vec<_>>();
+		let currency_width = lines.iter().map(|(currency, _)| AsRef::<str>::as_ref(currency).len()).max().unwrap_or_default();
+		let rate_width = lines.iter().map(|(_, rate)| rate.len()).max().unwrap_or_default();
+		for (i, (currency, rate)) in lines.iter().enumerate() {
+			if i > 0 { f.write_str("\n")?; }
+			write!(f, "{:<currency_width$} {rate:>rate_width$}", AsRef::<str>::as_ref(currency))?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::ConvertError;
+
+	#[test]
+	fn test_convert() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		rates.push(ILS, 3.1);
+		assert_eq!(rates.convert(&1234.0, USD, USD), Some(1234.));
+		assert_eq!(rates.convert(&1234.0, EUR, EUR), Some(1234.));
+		assert_eq!(rates.convert(&1234.0, ILS, ILS), Some(1234.));
+		assert_eq!(rates.convert(&1.0, ILS, EUR), Some(1. / 3.1 * 0.9));
+		assert_eq!(rates.convert(&1.0, EUR, ILS), Some(1. / 0.9 * 3.1));
+		assert_eq!(rates.convert_many(&[1.0, 2.0, 0.0], ILS, EUR), Some(vec![1. / 3.1 * 0.9, 2. * (0.9 / 3.1), 0.]));
+		assert_eq!(rates.convert_many(&[], USD, EUR), Some(vec![]));
+		assert_eq!(rates.convert_many(&[1.0], USD, GBP), None);
+
+		assert_eq!(rates.try_convert(&1.0, EUR, ILS), Ok(1. / 0.9 * 3.1));
+		assert_eq!(rates.try_convert(&1.0, GBP, ILS), Err(ConvertError::MissingFrom(GBP)));
+		assert_eq!(rates.try_convert(&1.0, ILS, JPY), Err(ConvertError::MissingTo(JPY)));
+		let invalid = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.0), (ILS, f64::NAN)]).unwrap();
+		assert_eq!(invalid.try_convert(&1.0, EUR, USD), Err(ConvertError::InvalidRate(EUR)));
+		assert_eq!(invalid.try_convert(&1.0, ILS, USD), Err(ConvertError::InvalidRate(ILS)));
+		assert_eq!(invalid.convert(&1.0, EUR, USD), Some(f64::INFINITY));
+
+		assert_eq!(rates.ratio(USD, USD), Some(1.0));
+		assert_eq!(rates.ratio(EUR, ILS), rates.convert(&1.0, EUR, ILS));
+		assert!((rates.ratio(EUR, USD).unwrap() - 1.0 / rates.ratio(USD, EUR).unwrap()).abs() < 1e-12);
+		assert_eq!(rates.ratio(GBP, USD), None);
+	}
+
+	#[test]
+	fn test_total() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.5), (ILS, 4.0)]).unwrap();
+		assert_eq!(rates.total([(USD, 10.0), (EUR, 5.0), (ILS, 8.0)], USD), Some(10.0 + 10.0 + 2.0));
+		assert_eq!(rates.total([(USD, 10.0), (USD, 2.0)], EUR), Some(6.0));
+		assert_eq!(rates.total([], ILS), Some(0.0));
+		assert_eq!(rates.total([], GBP), None);
+		assert_eq!(rates.total([(USD, 10.0), (GBP, 1.0)], USD), None);
+	}
+
+	#[test]
+	fn test_rebase() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.5), (ILS, 4.0), (BTC, 0.0)]).unwrap();
+		assert_eq!(rates.rebase(GBP), Err(RebaseError::Missing(GBP)));
+		assert_eq!(rates.rebase(BTC), Err(RebaseError::Zero(BTC)));
+		assert_eq!(rates.rates(), [1.0, 0.5, 4.0, 0.0]);
+		rates.rebase(EUR).unwrap();
+		assert_eq!(rates.rates(), [2.0, 1.0, 8.0, 0.0]);
+		assert_eq!(rates.convert(&1.0, USD, ILS), Some(4.0));
+	}
+
+	#[test]
+	fn test_invert() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.5), (ILS, 4.0)]).unwrap();
+		let inverted = rates.inverted().unwrap();
+		assert_eq!(inverted.rates(), [1.0, 2.0, 0.25]);
+		rates.invert().unwrap();
+		assert!(rates.eq_ordered(&inverted));
+
+		rates.push(BTC, 0.0);
+		assert_eq!(rates.inverted().unwrap_err(), ZeroRateError(BTC));
+		assert_eq!(rates.invert(), Err(ZeroRateError(BTC)));
+		assert_eq!(rates.rates(), [1.0, 2.0, 0.25, 0.0]);
+	}
+
+	#[cfg(feature = "binary-serde")]
+	#[test]
+	fn test_postcard() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.9), (AVAX, 0.04)]).unwrap();
+		let bytes = rates.to_postcard().unwrap();
+		assert_eq!(bytes.len(), 1 + 3 * (8 + 8));
+		assert_eq!(&bytes[1..9], b"AVAX\0\0\0\0");
+		assert_eq!(Rates::<f64, 3>::from_postcard(&bytes).unwrap(), rates);
+		assert!(Rates::<f64, 2>::from_postcard(&bytes).is_err());
+
+		let mut invalid = bytes.clone();
+		invalid[1] = b'-';
+		assert!(Rates::<f64, 3>::from_postcard(&invalid).is_err());
+		let mut invalid = bytes.clone();
+		invalid[2] = b'v';
+		assert!(Rates::<f64, 3>::from_postcard(&invalid).is_err());
+		let mut invalid = bytes;
+		invalid[8] = b'X';
+		assert!(Rates::<f64, 3>::from_postcard(&invalid).is_err());
+
+		let bytes = postcard::to_allocvec(&rates).unwrap();
+		assert_eq!(&bytes[1..6], b"\x04AVAX");
+		assert_eq!(postcard::from_bytes::<Rates<f64, 3>>(&bytes).unwrap(), rates);
+	}
+
+	#[test]
+	fn test_from_entries() {
+		use crate::currency::*;
+		const RATES: Rates<f64, 4> = Rates::from_entries([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]);
+		assert!(RATES.eq_ordered(&Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap()));
+		assert_eq!(RATES.get(EUR), Some(&0.9));
+		assert!(Rates::<f64, 0>::from_entries([]).is_empty());
+
+		let drops = std::cell::Cell::new(0);
+		let rates = Rates::<_, 2>::from_entries([(USD, DropCounter(&drops)), (EUR, DropCounter(&drops))]);
+		assert_eq!(drops.get(), 0);
+		drop(rates);
+		assert_eq!(drops.get(), 2);
+	}
+
+	#[test]
+	fn test_updated_at() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 2>::try_from_iter([(USD, 1.0), (EUR, 0.9)]).unwrap();
+		assert_eq!(rates.age(SystemTime::now()), None);
+		assert!(rates.is_stale(Duration::MAX));
+
+		let now = SystemTime::now();
+		rates.set_updated_at(Some(now - Duration::from_secs(60)));
+		assert_eq!(rates.age(now), Some(Duration::from_secs(60)));
+		assert_eq!(rates.age(now - Duration::from_secs(120)), Some(Duration::ZERO));
+		assert!(rates.is_stale(Duration::from_secs(30)));
+		assert!(!rates.is_stale(Duration::from_secs(3600)));
+		assert_eq!(rates.clone().updated_at(), rates.updated_at());
+		assert_eq!(rates.map_rates_ref(|&rate| rate as f32).updated_at(), rates.updated_at());
+	}
+
+	#[test]
+	fn test_mul() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.5), (ILS, 4.0)]).unwrap();
+		assert_eq!((&rates * 100.0).rates(), [100.0, 50.0, 400.0]);
+		rates *= 2.0;
+		assert_eq!(rates.rates(), [2.0, 1.0, 8.0]);
+		assert_eq!((rates.clone() * 0.5).rates(), [1.0, 0.5, 4.0]);
+
+		assert_eq!((&rates * f64::MAX).rates(), [f64::INFINITY, f64::MAX, f64::INFINITY]);
+		assert!((rates * f64::NAN).rates().iter().all(|rate| rate.is_nan()));
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	#[should_panic(expected = "Multiplication overflowed")]
+	fn test_mul_decimal_overflow() {
+		use crate::currency::*;
+		use rust_decimal::Decimal;
+		let mut rates = Rates::<Decimal, 2>::try_from_iter([(USD, Decimal::ONE), (EUR, Decimal::TWO)]).unwrap();
+		rates *= Decimal::TEN;
+		assert_eq!(rates.rates(), [Decimal::TEN, Decimal::from(20)]);
+		rates *= Decimal::MAX;
+	}
+
+	#[test]
+	fn test_pairs() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap();
+		assert!(rates.to_pairs().eq([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]));
+
+		let mut pairs = [(JPY, 0.0); 4];
+		assert_eq!(rates.copy_to_pairs(&mut pairs), 3);
+		assert_eq!(pairs, [(USD, 1.0), (EUR, 0.9), (ILS, 3.1), (JPY, 0.0)]);
+		let mut pairs = [(JPY, 0.0); 2];
+		assert_eq!(rates.copy_to_pairs(&mut pairs), 2);
+		assert_eq!(pairs, [(USD, 1.0), (EUR, 0.9)]);
+	}
+
+	#[test]
+	fn test_margin() {
+		use crate::currency::*;
+		let mid = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.5), (ILS, 4.0)]).unwrap();
+		let mut rates = mid.with_margin(1.5);
+		assert_eq!(rates.rates(), [1.5, 0.75, 6.0]);
+		assert_eq!(mid.rates(), [1.0, 0.5, 4.0]);
+
+		rates.apply_margin(2.0);
+		assert_eq!(rates.rates(), [3.0, 1.5, 12.0]);
+
+		let mut rates = mid.clone();
+		rates.apply_margin_with(|currency| if currency == ILS { 1.5 } else { 1.0 });
+		assert_eq!(rates.rates(), [1.0, 0.5, 6.0]);
+	}
+
+	#[test]
+	fn test_round_all() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.91234), (ILS, 3.70561)]).unwrap();
+		rates.round_all(2);
+		assert_eq!(rates.rates(), [1.0, 0.91, 3.71]);
+		assert_eq!(rates.convert(&100.0, USD, EUR), Some(91.0));
+		assert_eq!(rates.convert(&100.0, USD, ILS), Some(371.0));
+		rates.round_all(400);
+		assert_eq!(rates.rates(), [1.0, 0.91, 3.71]);
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_round_all_decimal() {
+		use crate::currency::*;
+		use rust_decimal::Decimal;
+		let d = |s: &str| s.parse::<Decimal>().unwrap();
+		let mut rates = Rates::<Decimal, 2>::try_from_iter([(USD, Decimal::ONE), (EUR, d("0.91250"))]).unwrap();
+		rates.round_all(2);
+		assert_eq!(rates.rates(), [Decimal::ONE, d("0.91")]);
+		assert_eq!(rates.convert(&Decimal::TEN, USD, EUR), Some(d("9.1")));
+	}
+
+	#[test]
+	fn test_reciprocal() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.8), (BTC, 0.0)]).unwrap();
+		assert_eq!(rates.reciprocal(USD), Some(1.0));
+		assert_eq!(rates.reciprocal(EUR), Some(1.25));
+		assert_eq!(rates.reciprocal(BTC), None);
+		assert_eq!(rates.reciprocal(ILS), None);
+	}
+
+	#[test]
+	fn test_convert_all() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.5), (ILS, 4.0), (EUR, 0.25)]).unwrap();
+		let converted = rates.convert_all(&10.0, ILS, true).unwrap().collect::<Vec<_>>();
+		assert_eq!(converted, [(USD, 2.5), (ILS, 10.0), (EUR, 0.625)]);
+		let converted = rates.convert_all(&10.0, ILS, false).unwrap().collect::<Vec<_>>();
+		assert_eq!(converted, [(USD, 2.5), (EUR, 0.625)]);
+		assert!(rates.convert_all(&10.0, GBP, true).is_none());
+		assert!(rates.convert_all(&10.0, GBP, false).is_none());
+	}
+
+	#[test]
+	fn test_cross_matrix() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		rates.push(ILS, 3.1);
+		let matrix = rates.cross_matrix();
+		assert_eq!(matrix.len(), 9);
+		for &(from, to, factor) in &matrix {
+			if from == to { assert_eq!(factor, 1.0); }
+			assert_eq!(Some(factor), rates.convert(&1.0, from, to));
+		}
+		assert_eq!(matrix[1], (USD, EUR, 0.9));
+	}
+
+	#[test]
+	fn test_convert_rounded() {
+		use crate::{currency::*, RoundingMode};
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		rates.push(ILS, 3.1);
+		assert_eq!(rates.convert_rounded(&1.0, ILS, EUR, 2, RoundingMode::HalfUp), Some(0.29));
+		assert_eq!(rates.convert_rounded(&10.0, USD, ILS, 0, RoundingMode::HalfEven), Some(31.0));
+		assert_eq!(rates.convert_rounded(&10.0, USD, GBP, 0, RoundingMode::HalfEven), None);
+		assert_eq!(rates.convert_rounded(&10.0, USD, ILS, 400, RoundingMode::HalfUp), rates.convert(&10.0, USD, ILS));
+		assert_eq!(rates.convert_rounded(&10.0, USD, ILS, u32::MAX, RoundingMode::HalfEven), rates.convert(&10.0, USD, ILS));
+
+		let rates = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (JPY, 149.738221), (BHD, 0.376)]).unwrap();
+		assert_eq!(rates.convert_rounded_minor(&10.0, USD, JPY, RoundingMode::HalfUp), Some(1497.0));
+		assert_eq!(rates.convert_rounded_minor(&1.0, JPY, USD, RoundingMode::HalfUp), Some(0.01));
+		assert_eq!(rates.convert_rounded_minor(&1000.0, JPY, BHD, RoundingMode::HalfEven), Some(2.511));
+		assert_eq!(rates.convert_rounded_minor(&1.0, USD, GBP, RoundingMode::HalfUp), None);
+	}
+
+	#[test]
+	fn test_duplicates() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 10>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 2.0);
+		assert_eq!(rates.get(USD).unwrap(), &1.0);
+		rates.push(USD, 3.0);
+		assert_eq!(rates.get(USD).unwrap(), &3.0);
+	}
+
+	#[test]
+	fn test_sorted_lookup() {
+		use crate::currency::*;
+		let mut rates = Rates::<usize, { ARRAY.len() }>::new();
+		for (i, &currency) in ARRAY.iter().enumerate().rev() { rates.push(currency, i); }
+		for (i, &currency) in ARRAY.iter().enumerate() { assert_eq!(rates.get(currency), Some(&i)); }
+		rates.sort();
+		assert!(rates.currencies().windows(2).all(|w| w[0] <= w[1]));
+		for (i, &currency) in ARRAY.iter().enumerate() { assert_eq!(rates.get(currency), Some(&i)); }
+
+		let mut rates = Rates::<usize, 10>::new();
+		rates.extend_capped([(USD, 0), (EUR, 1), (ILS, 2), (EUR, 3)]);
+		assert!(!rates.is_sorted());
+		rates.sort();
+		assert_eq!(rates.get(USD), Some(&0));
+		assert_eq!(rates.get(EUR), Some(&3));
+		assert_eq!(rates.get(ILS), Some(&2));
+		assert_eq!(rates.get(GBP), None);
+	}
+
+	#[test]
+	fn test_sorted_lookup_matches_linear() {
+		use crate::currency::*;
+		let mut rates = Rates::<usize, { ARRAY.len() + 10 }>::new();
+		for (i, &currency) in ARRAY.iter().enumerate().filter(|(i, _)| i % 3 != 0) { rates.push(currency, i); }
+		for (i, &currency) in ARRAY.iter().enumerate().step_by(7) { rates.push(currency, i + 1000); }
+		let linear = |rates: &Rates<usize, { ARRAY.len() + 10 }>, currency| rates.iter().find(|&(c, _)| c == currency).map(|(_, &r)| r);
+		let unsorted = rates.clone();
+		rates.sort();
+		assert!(!unsorted.is_sorted() && rates.is_sorted());
+		for currency in ARRAY {
+			assert_eq!(rates.get(currency).copied(), linear(&unsorted, currency));
+			assert_eq!(unsorted.get(currency).copied(), linear(&unsorted, currency));
+		}
+	}
+
+	#[test]
+	fn test_collection_traits() {
+		use crate::currency::*;
+		let pairs = vec![(USD, 1.0), (EUR, 0.9), (ILS, 3.1)];
+		let rates = Rates::<f64, 3>::try_from(pairs.as_slice()).unwrap();
+		assert_eq!(rates.currencies().iter().copied().zip(rates.rates().iter().copied()).collect::<Vec<_>>(), pairs);
+		assert_eq!(
+			Rates::<f64, 2>::try_from(pairs.as_slice()).unwrap_err(),
+			CapacityError { currency: ILS, rate: 3.1 },
+		);
+		assert_eq!(Rates::<f64, 3>::try_from_iter(pairs.iter().copied()).unwrap().len(), 3);
+
+		let mut rates = Rates::<f64, 2>::new();
+		assert_eq!(rates.try_extend(pairs.iter().copied()), Err(CapacityError { currency: ILS, rate: 3.1 }));
+		assert_eq!(rates.currencies(), [USD, EUR]);
+
+		let mut rates = Rates::<f64, 3>::new();
+		rates.extend([(USD, 1.0), (EUR, 0.9)]);
+		assert_eq!(rates.currencies(), [USD, EUR]);
+		assert!(std::panic::catch_unwind(move || rates.extend(pairs)).is_err());
+	}
+
+	#[test]
+	fn test_serialize() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 10>::new();
+		assert_eq!(serde_json::to_string(&rates).unwrap(), "{}");
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		rates.push(USD, 1.1);
+		assert_eq!(serde_json::to_string(&rates).unwrap(), r#"{"EUR":0.9,"USD":1.1}"#);
+
+		let shuffled = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (ILS, 3.1), (EUR, 0.9)]).unwrap();
+		let mut sorted = shuffled.clone();
+		sorted.sort();
+		let json = r#"{"EUR":0.9,"ILS":3.1,"USD":1.0}"#;
+		assert_eq!(serde_json::to_string(&shuffled).unwrap(), json);
+		assert_eq!(serde_json::to_string(&sorted).unwrap(), json);
+		assert_eq!(serde_json::from_str::<Rates<f64, 3>>(json).unwrap(), shuffled);
+	}
+
+	#[test]
+	fn test_deserialize() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		let json = serde_json::to_string(&rates).unwrap();
+		let deserialized = serde_json::from_str::<Rates<f64, 3>>(&json).unwrap();
+		assert!(deserialized.is_sorted());
+		assert_eq!(serde_json::to_string(&deserialized).unwrap().len(), json.len());
+		assert_eq!(deserialized.get(USD), Some(&1.0));
+		assert_eq!(deserialized.get(EUR), Some(&0.9));
+
+		assert!(serde_json::from_str::<Rates<f64, 2>>(r#"{"USD":1,"EUR":0.9,"ILS":3.1}"#).is_err());
+		assert!(serde_json::from_str::<Rates<f64, 2>>(r#"{"USD":1,"E":0.9}"#).is_err());
+
+		let deserialized = serde_json::from_str::<Rates<f64, 3>>(r#"{"USD":"1","EUR":"9E-1","ILS":3.1}"#).unwrap();
+		assert_eq!(deserialized, Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap());
+		let error = serde_json::from_str::<Rates<f64, 3>>(r#"{"USD":"one"}"#).unwrap_err().to_string();
+		assert!(error.contains("invalid rate \"one\""), "{error}");
+		assert!(serde_json::from_str::<Rates<f64, 3>>(r#"{"USD":true}"#).is_err());
+		let deserialized = serde_json::from_str::<Rates<i64, 2>>(r#"{"USD":1,"JPY":1.5e2}"#).unwrap();
+		assert_eq!(deserialized, Rates::<i64, 2>::try_from_iter([(USD, 1), (JPY, 150)]).unwrap());
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_deserialize_decimal() {
+		use crate::currency::*;
+		use rust_decimal::Decimal;
+		let d = |s: &str| s.parse::<Decimal>().unwrap();
+		let json = r#"{"EUR":0.1,"ILS":3.1234567890123456789,"USD":1}"#;
+		let rates = serde_json::from_str::<Rates<Decimal, 3>>(json).unwrap();
+		assert_eq!(rates.get(EUR), Some(&d("0.1")));
+		assert_eq!(rates.get(ILS), Some(&d("3.1234567890123456789")));
+		assert_eq!(rates.get(USD), Some(&Decimal::ONE));
+		let deserialized = serde_json::from_str::<Rates<Decimal, 3>>(&serde_json::to_string(&rates).unwrap()).unwrap();
+		assert_eq!(deserialized, rates);
+	}
+
+	#[test]
+	fn test_merge() {
+		use crate::currency::*;
+		let mut majors = Rates::<f64, 3>::new();
+		majors.push(USD, 1.0);
+		majors.push(EUR, 0.9);
+		let mut exotics = Rates::<f64, 3>::new();
+		exotics.push(EUR, 0.8);
+		exotics.push(ILS, 3.1);
+		exotics.push(GBP, 0.7);
+
+		let mut merged = majors.clone();
+		assert_eq!(merged.merge(&exotics, MergePolicy::KeepSelf), MergeSummary { added: 1, shared: 1, dropped: 1 });
+		assert_eq!(merged.currencies(), [USD, EUR, ILS]);
+		assert_eq!(merged.get(EUR), Some(&0.9));
+
+		let mut merged = majors.clone();
+		merged.merge(&exotics, MergePolicy::KeepOther);
+		assert_eq!(merged.get(EUR), Some(&0.8));
+		assert_eq!(merged.merge(&majors, MergePolicy::KeepOther), MergeSummary { added: 0, shared: 2, dropped: 0 });
+		assert_eq!(merged.get(EUR), Some(&0.9));
+
+		let mut merged = majors.clone();
+		merged.merge_with(&exotics, |currency, existing, incoming| {
+			assert_eq!(currency, EUR);
+			existing + incoming
+		});
+		assert_eq!(merged.get(EUR), Some(&(0.9 + 0.8)));
+	}
+
+	#[test]
+	fn test_display() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (ILS, 3.1), (EUR, 0.9)]).unwrap();
+		assert_eq!(rates.to_string(), "USD   1\nILS 3.1\nEUR 0.9");
+		rates.push(BTC, 0.00002);
+		assert_eq!(format!("{rates:#.3}"), "BTC 0.000\nEUR 0.900\nILS 3.100\nUSD 1.000");
+		assert_eq!(Rates::<f64, 1>::new().to_string(), "");
+	}
+
+	#[test]
+	fn test_maps() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1), (EUR, 0.8)]).unwrap();
+		let expected = HashMap::from([(USD, 1.0), (EUR, 0.8), (ILS, 3.1)]);
+		assert_eq!(rates.to_hash_map(), expected);
+		assert_eq!(rates.clone().into_hash_map(), expected);
+		assert_eq!(Rates::<f64, 3>::try_from(expected.clone()).unwrap(), rates);
+		assert!(Rates::<f64, 2>::try_from(expected).is_err());
+
+		let btree = rates.clone().into_btree_map();
+		assert_eq!(btree, BTreeMap::from([(USD, 1.0), (EUR, 0.8), (ILS, 3.1)]));
+		let from_btree = Rates::<f64, 3>::try_from(btree.clone()).unwrap();
+		assert!(from_btree.is_sorted());
+		assert_eq!(from_btree, rates);
+		let last = *btree.keys().next_back().unwrap();
+		assert_eq!(Rates::<f64, 2>::try_from(btree).unwrap_err().currency, last);
+	}
+
+	#[test]
+	fn test_eq() {
+		use crate::currency::*;
+		let a = Rates::<f64, 3>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap();
+		let b = Rates::<f64, 4>::try_from_iter([(ILS, 3.1), (USD, 1.0), (EUR, 0.9)]).unwrap();
+		assert_eq!(a, b);
+		assert!(!a.eq_ordered(&b));
+		assert!(a.eq_ordered(&a.clone()));
+		assert_ne!(a, Rates::<f64, 3>::try_from_iter([(ILS, 3.1), (USD, 1.0), (EUR, 0.8)]).unwrap());
+		assert_ne!(a, Rates::<f64, 3>::try_from_iter([(ILS, 3.1), (USD, 1.0)]).unwrap());
+		assert_eq!(b, Rates::<f64, 4>::try_from_iter([(ILS, 3.0), (USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap());
+	}
+
+	#[test]
+	fn test_fingerprint() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap();
+		let permuted = Rates::<f64, 4>::try_from_iter([(ILS, 3.0), (EUR, 0.9), (USD, 1.0), (ILS, 3.1)]).unwrap();
+		assert_eq!(rates.fingerprint(), permuted.fingerprint());
+		let changed = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.91), (ILS, 3.1)]).unwrap();
+		assert_ne!(rates.fingerprint(), changed.fingerprint());
+		let swapped = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 3.1), (ILS, 0.9)]).unwrap();
+		assert_ne!(rates.fingerprint(), swapped.fingerprint());
+	}
+
+	#[test]
+	fn test_clone() {
+		use crate::currency::*;
+		let mut rates = Rates::<String, 10>::new();
+		rates.push(USD, "1.0".to_owned());
+		rates.push(EUR, "0.9".to_owned());
+		let clone = rates.clone();
+		assert_eq!(clone, rates);
+		assert_ne!(clone[USD].as_ptr(), rates[USD].as_ptr());
+		rates.push(USD, "1.1".to_owned());
+		assert_eq!(clone.len(), 2);
+		assert_eq!(clone.get(USD).unwrap(), "1.0");
+
+		let mut target = Rates::<String, 10>::new();
+		target.push(ILS, "3.1".to_owned());
+		target.clone_from(&rates);
+		assert!(target.iter().eq(rates.iter()));
+		assert_ne!(target[EUR].as_ptr(), rates[EUR].as_ptr());
+		target.clone_from(&clone);
+		assert!(target.iter().eq(clone.iter()));
+		assert_eq!(target.is_sorted(), clone.is_sorted());
+	}
+
+	#[test]
+	fn test_mutable_access() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 10>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		rates.push(ILS, 3.1);
+		*rates.get_mut(ILS).unwrap() = 3.2;
+		assert_eq!(rates.get(ILS), Some(&3.2));
+		assert_eq!(rates.get_mut(GBP), None);
+		for (currency, rate) in rates.iter_mut() {
+			if currency != USD { *rate *= 2.0; }
+		}
+		assert_eq!(rates.get(USD), Some(&1.0));
+		assert_eq!(rates.get(EUR), Some(&1.8));
+		assert_eq!(rates.get(ILS), Some(&6.4));
+	}
+
+	#[test]
+	fn test_rates_mut() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap();
+		for rate in rates.rates_mut() { *rate *= 2.0; }
+		assert_eq!(rates.rates(), [2.0, 1.8, 6.2]);
+		assert_eq!(rates.get(EUR), Some(&1.8));
+		assert!(Rates::<f64, 4>::new().rates_mut().is_empty());
+	}
+
+	#[test]
+	fn test_try_push() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 2>::new();
+		assert_eq!(rates.try_push(USD, 1.0), Ok(()));
+		assert_eq!(rates.try_push(USD, 1.1), Ok(()));
+		assert_eq!(rates.try_push(EUR, 0.9), Err(CapacityError { currency: EUR, rate: 0.9 }));
+		assert_eq!(rates.get(USD), Some(&1.1));
+		assert!(!rates.push(EUR, 0.9));
+	}
+
+	#[test]
+	fn test_capacity() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		assert_eq!((rates.capacity(), rates.remaining()), (3, 3));
+		assert_eq!(rates.extend_capped([(USD, 1.0), (EUR, 0.9)]), 2);
+		assert_eq!((rates.capacity(), rates.remaining()), (3, 1));
+		assert!(!rates.is_full());
+		assert_eq!(rates.extend_capped([(ILS, 3.1), (GBP, 0.8)]), 1);
+		assert_eq!((rates.capacity(), rates.remaining()), (3, 0));
+		assert!(rates.is_full());
+		assert_eq!(rates.extend_capped([(GBP, 0.8)]), 0);
+	}
+
+	#[test]
+	fn test_insert_or_update() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 2>::new();
+		assert_eq!(rates.insert_or_update(USD, 1.0), Ok(None));
+		rates.push(EUR, 0.9);
+		assert_eq!(rates.insert_or_update(EUR, 0.95), Ok(Some(0.9)));
+		assert_eq!(rates.insert_or_update(ILS, 3.1), Err(3.1));
+		assert_eq!(rates.len(), 2);
+		assert_eq!(rates.get(EUR), Some(&0.95));
+	}
+
+	#[test]
+	fn test_lookup() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		assert!(rates.contains(EUR));
+		assert!(!rates.contains(ILS));
+		assert_eq!(rates.index_of(EUR), Some(1));
+		assert_eq!(rates.index_of(ILS), None);
+		assert_eq!(rates.get_by_str("EUR"), Some(&0.9));
+		assert_eq!(rates.get_by_str("ILS"), None);
+		assert_eq!(rates.get_by_str("E"), None);
+	}
+
+	#[test]
+	fn test_index() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		assert_eq!(rates[EUR], 0.9);
+		assert_eq!(rates["USD"], 1.0);
+	}
+
+	#[test]
+	#[should_panic(expected = "missing ILS rate")]
+	fn test_index_missing() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::new();
+		let _ = rates[ILS];
+	}
+
+	#[test]
+	#[should_panic(expected = "missing ILS rate")]
+	fn test_index_str_missing() {
+		let _ = Rates::<f64, 3>::new()["ILS"];
+	}
+
+
+	#[test]
+	fn test_get_mut() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 3>::new();
+		rates.push(USD, 1.0);
+		rates.push(EUR, 0.9);
+		*rates.get_mut(EUR).unwrap() += 0.1;
+		assert_eq!(rates.get(EUR), Some(&1.0));
+		assert_eq!(rates.get(USD), Some(&1.0));
+	}
+
+	#[test]
+	fn test_remove() {
+		use crate::currency::*;
+		let mut rates = Rates::<String, 10>::new();
+		rates.push(USD, "1.0".to_owned());
+		rates.push(EUR, "0.9".to_owned());
+		rates.push(ILS, "3.1".to_owned());
+		rates.push(GBP, "0.8".to_owned());
+		rates.push(EUR, "0.95".to_owned());
+		assert_eq!(rates.remove(EUR).as_deref(), Some("0.95"));
+		assert_eq!(rates.remove(EUR), None);
+		assert_eq!(rates.currencies(), [USD, ILS, GBP]);
+		assert_eq!(rates.swap_remove(USD).as_deref(), Some("1.0"));
+		assert_eq!(rates.currencies(), [GBP, ILS]);
+		assert_eq!(rates.get(ILS).unwrap(), "3.1");
+		assert_eq!(rates.swap_remove(ILS).as_deref(), Some("3.1"));
+		assert_eq!(rates.swap_remove(GBP).as_deref(), Some("0.8"));
+		assert!(rates.is_empty());
+
+		let mut rates = Rates::<f64, 4>::new();
+		for (currency, rate) in [(USD, 1.0), (EUR, 2.0), (USD, 3.0), (EUR, 4.0)] { rates.push(currency, rate); }
+		assert_eq!(rates.swap_remove(USD), Some(3.0));
+		assert_eq!(rates.get(EUR), Some(&4.0));
+		assert_eq!(rates.currencies(), [EUR, EUR]);
+		let mut rates = Rates::<f64, 4>::new();
+		for (currency, rate) in [(USD, 1.0), (EUR, 2.0), (ILS, 3.0), (EUR, 4.0)] { rates.push(currency, rate); }
+		assert_eq!(rates.swap_remove(USD), Some(1.0));
+		assert_eq!(rates.get(EUR), Some(&4.0));
+		assert_eq!(rates.get(ILS), Some(&3.0));
+	}
+
+	#[test]
+	fn test_retain() {
+		use crate::currency::*;
+		let mut rates = Rates::<String, 10>::new();
+		rates.retain(|_, _| false);
+		assert!(rates.is_empty());
+		rates.push(USD, "1.0".to_owned());
+		rates.push(EUR, "0.9".to_owned());
+		rates.push(ILS, "3.1".to_owned());
+		rates.push(GBP, "0.8".to_owned());
+		rates.retain(|_, _| true);
+		assert_eq!(rates.currencies(), [USD, EUR, ILS, GBP]);
+		rates.retain(|currency, rate| currency != EUR && rate != "0.8");
+		assert_eq!(rates.currencies(), [USD, ILS]);
+		assert!(rates.iter().eq([(ILS, &"3.1".to_owned()), (USD, &"1.0".to_owned())]));
+	}
+
+	#[test]
+	fn test_get_or_insert() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 2>::new();
+		*rates.get_or_insert(USD, 0.0) += 1.0;
+		*rates.get_or_insert(USD, 0.0) += 1.0;
+		assert_eq!(rates.get(USD), Some(&2.0));
+		assert_eq!(rates.get_or_insert(EUR, 0.9), &mut 0.9);
+		assert_eq!(rates.try_get_or_insert(EUR, 0.0), Ok(&mut 0.9));
+		assert_eq!(rates.try_get_or_insert(ILS, 3.1), Err(3.1));
+		assert_eq!(rates.len(), 2);
+		assert!(std::panic::catch_unwind(move || { rates.get_or_insert(ILS, 3.1); }).is_err());
+	}
+
+	#[test]
+	fn test_new_boxed() {
+		use crate::currency::*;
+		// larger than the default test thread stack.
+		let mut rates = Rates::<[u64; 128], 4096>::new_boxed();
+		assert!(rates.is_empty() && rates.is_sorted());
+		rates.push(USD, [1; 128]);
+		rates.push(EUR, [2; 128]);
+		assert_eq!(rates.get(EUR), Some(&[2; 128]));
+		assert_eq!(rates.len(), 2);
+	}
+
+	#[test]
+	fn test_default_capacity() {
+		use crate::currency::*;
+		let rates = Rates::<f64>::try_from_iter(ARRAY.iter().map(|&currency| (currency, 1.0))).unwrap();
+		assert_eq!(rates.len(), ARRAY.len());
+	}
+
+	#[test]
+	fn test_large_capacity() {
+		use crate::currency::*;
+		const N: usize = 300;
+		let mut rates = Rates::<usize, N>::new_boxed();
+		for i in 0..N { assert!(rates.push(ARRAY[i % ARRAY.len()], i)); }
+		assert!(!rates.push(USD, N));
+		assert_eq!(rates.len(), N);
+		assert_eq!(rates.rates().last(), Some(&(N - 1)));
+		assert_eq!(rates.get(ARRAY[(N - 1) % ARRAY.len()]), Some(&(N - 1)));
+		rates.retain(|_, &rate| rate >= 1);
+		assert_eq!(rates.len(), N - 1);
+	}
+
+	#[test]
+	fn test_retain_threshold() {
+		use crate::currency::*;
+		let mut rates = Rates::<f64, 10>::try_from_iter([(EUR, 0.9), (JPY, 149.5), (ILS, 3.1), (KRW, 1337.2), (USD, 1.0)]).unwrap();
+		rates.sort();
+		rates.retain(|_, &rate| rate <= 100.0);
+		assert_eq!(rates, Rates::<f64, 3>::try_from_iter([(EUR, 0.9), (ILS, 3.1), (USD, 1.0)]).unwrap());
+		assert!(rates.is_sorted());
+		assert_eq!(rates.get(JPY), None);
+		assert_eq!(rates.get(USD), Some(&1.0));
+	}
+
+	/// A rate that counts its drops.
+	#[derive(Debug)]
+	struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+
+	impl Drop for DropCounter<'_> {
+		fn drop(&mut self) { self.0.set(self.0.get() + 1); }
+	}
+
+	#[test]
+	fn test_drop() {
+		use crate::currency::*;
+		let drops = std::cell::Cell::new(0);
+		let mut rates = Rates::<DropCounter, 10>::new();
+		rates.push(USD, DropCounter(&drops));
+		rates.push(EUR, DropCounter(&drops));
+		rates.push(ILS, DropCounter(&drops));
+		assert_eq!(drops.get(), 0);
+		drop(rates.remove(EUR));
+		assert_eq!(drops.get(), 1);
+		drop(rates.swap_remove(USD));
+		assert_eq!(drops.get(), 2);
+		rates.push(GBP, DropCounter(&drops));
+		rates.retain(|currency, _| currency == GBP);
+		assert_eq!(drops.get(), 3);
+		drop(rates);
+		assert_eq!(drops.get(), 4);
+
+		let drops = std::cell::Cell::new(0);
+		let mut rates = Rates::<DropCounter, 10>::new();
+		rates.push(USD, DropCounter(&drops));
+		rates.push(EUR, DropCounter(&drops));
+		rates.clear();
+		assert_eq!(drops.get(), 2);
+		assert!(rates.is_empty());
+		rates.push(ILS, DropCounter(&drops));
+		assert!(rates.insert_or_update(ILS, DropCounter(&drops)).is_ok_and(|replaced| replaced.is_some()));
+		assert_eq!(drops.get(), 3);
+		drop(rates);
+		assert_eq!(drops.get(), 4);
+	}
+
+	#[test]
+	fn test_map_rates() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1)]).unwrap();
+		let strings = rates.map_rates_ref(f64::to_string);
+		assert!(strings.currencies() == rates.currencies() && strings.rates() == ["1", "0.9", "3.1"]);
+		assert!(strings.clone().map_rates(|rate| rate.parse::<f64>().unwrap()).eq_ordered(&rates));
+		assert_eq!(strings.try_map_rates(|rate| rate.parse::<u8>()).unwrap_err().0, EUR);
+
+		let drops = std::cell::Cell::new(0);
+		let mut counters = Rates::<DropCounter, 4>::new();
+		counters.push(USD, DropCounter(&drops));
+		counters.push(EUR, DropCounter(&drops));
+		counters.push(ILS, DropCounter(&drops));
+		let error = counters.try_map_rates(|rate| if drops.get() == 0 { drop(rate); Ok(()) } else { Err(rate) }).unwrap_err();
+		assert_eq!(error.0, EUR);
+		assert_eq!(drops.get(), 2);
+		drop(error);
+		assert_eq!(drops.get(), 3);
+	}
+
+	#[test]
+	fn test_subset() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.9), (ILS, 3.1), (GBP, 0.8)]).unwrap();
+		let subset = rates.subset::<3>(&[GBP, USD, GBP]).unwrap();
+		assert_eq!(subset.currencies(), [GBP, USD]);
+		assert_eq!(subset.rates(), [0.8, 1.0]);
+		assert_eq!(rates.subset::<3>(&[EUR, JPY, USD, KRW]), Err(SubsetError::Missing(vec![JPY, KRW])));
+		let error = rates.subset::<3>(&[KRW, JPY, USD, KRW, KRW]).unwrap_err();
+		assert_eq!(error, SubsetError::Missing(vec![KRW, JPY]));
+		assert_eq!(error.to_string(), "missing the rates of 2 currencies");
+		assert_eq!(rates.subset::<2>(&[EUR, USD, ILS]), Err(SubsetError::Capacity { capacity: 2 }));
+	}
+
+	#[test]
+	fn test_truncate_split_off() {
+		use crate::currency::*;
+		let drops = std::cell::Cell::new(0);
+		let mut rates = Rates::<DropCounter, 5>::new();
+		for currency in [USD, EUR, ILS, GBP, JPY] { rates.push(currency, DropCounter(&drops)); }
+		rates.truncate(5);
+		assert_eq!(drops.get(), 0);
+		rates.truncate(4);
+		assert_eq!((drops.get(), rates.currencies()), (1, [USD, EUR, ILS, GBP].as_slice()));
+
+		let tail = rates.split_off(2);
+		assert_eq!(drops.get(), 1);
+		assert_eq!((rates.currencies(), tail.currencies()), ([USD, EUR].as_slice(), [ILS, GBP].as_slice()));
+		assert!(rates.contains(EUR) && !rates.contains(ILS) && tail.contains(ILS) && !tail.contains(EUR));
+		drop(tail);
+		assert_eq!(drops.get(), 3);
+		assert!(rates.split_off(2).is_empty());
+		assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| rates.split_off(3))).is_err());
+		drop(rates);
+		assert_eq!(drops.get(), 5);
+	}
+
+	#[test]
+	fn test_diff() {
+		use crate::currency::*;
+		let previous = Rates::<f64, 4>::try_from_iter([(USD, 1.0), (EUR, 0.8), (ILS, 3.1), (GBP, 0.7)]).unwrap();
+		let current = Rates::<f64, 3>::try_from_iter([(JPY, 150.0), (EUR, 0.88), (USD, 1.0)]).unwrap();
+		let changes = current.diff(&previous).collect::<Vec<_>>();
+		assert_eq!(changes, [
+			RateChange::Added { currency: JPY, rate: &150.0 },
+			RateChange::Changed { currency: EUR, old: &0.8, new: &0.88 },
+			RateChange::Removed { currency: ILS, rate: &3.1 },
+			RateChange::Removed { currency: GBP, rate: &0.7 },
+		]);
+		assert_eq!(changes.iter().map(RateChange::currency).collect::<Vec<_>>(), [JPY, EUR, ILS, GBP]);
+		assert!((changes[1].relative_change().unwrap() - 0.1).abs() < 1e-12);
+		assert_eq!(changes[0].relative_change(), None);
+		assert_eq!(current.diff(&current).count(), 0);
+	}
+
+	#[test]
+	fn test_into_iter() {
+		use crate::currency::*;
+		let mut rates = Rates::<String, 4>::new();
+		rates.push(USD, "1.0".to_owned());
+		rates.push(EUR, "0.9".to_owned());
+		rates.push(ILS, "3.1".to_owned());
+		let expected = rates.iter().map(|(currency, rate)| (currency, rate.clone())).collect::<Vec<_>>();
+		assert_eq!(rates.clone().into_iter().collect::<Vec<_>>(), expected);
+		assert!(rates.clone().into_iter().rev().eq(expected.into_iter().rev()));
+
+		let drops = std::cell::Cell::new(0);
+		let rates = Rates::<_, 4>::try_from_iter([USD, EUR, ILS, GBP].map(|currency| (currency, DropCounter(&drops)))).unwrap();
+		let mut iter = rates.into_iter();
+		assert_eq!(iter.len(), 4);
+		assert_eq!(iter.next().map(|(currency, _)| currency), Some(GBP));
+		assert_eq!(drops.get(), 1);
+		assert_eq!(iter.next_back().map(|(currency, _)| currency), Some(USD));
+		assert_eq!(drops.get(), 2);
+		drop(iter);
+		assert_eq!(drops.get(), 4);
+
+		let rates = Rates::<_, 4>::try_from_iter([USD, EUR].map(|currency| (currency, DropCounter(&drops)))).unwrap();
+		drop(rates.into_iter());
+		assert_eq!(drops.get(), 6);
 	}
 }
@@ -0,0 +1,189 @@
+//! Heap-backed currency rates container.
+
+use std::fmt;
+
+use crate::{CapacityError, CurrencyCode, Rates, RateTable};
+
+/// Currency rates, backed by the heap.
+///
+/// Unlike [`Rates`], it has no capacity limit.
+#[derive(Clone)]
+pub struct RatesVec<RATE> {
+	currency: Vec<CurrencyCode>,
+	rate: Vec<RATE>,
+	/// Whether the currencies are sorted, enabling binary search.
+	sorted: bool,
+}
+
+impl<RATE> RatesVec<RATE> {
+	/// Creates a new [`RatesVec`] value.
+	#[inline] pub const fn new() -> Self { Self { currency: Vec::new(), rate: Vec::new(), sorted: true } }
+
+	/// Creates a new [`RatesVec`] value with space for at least `capacity` rates.
+	#[inline] pub fn with_capacity(capacity: usize) -> Self {
+		Self { currency: Vec::with_capacity(capacity), rate: Vec::with_capacity(capacity), sorted: true }
+	}
+
+	/// Gets the count of rates.
+	#[inline] pub fn len(&self) -> usize { self.currency.len() }
+	/// Gets whether there are no rates.
+	#[inline] pub fn is_empty(&self) -> bool { self.currency.is_empty() }
+	/// Removes all rates.
+	#[inline] pub fn clear(&mut self) { self.currency.clear(); self.rate.clear(); self.sorted = true; }
+
+	/// Gets a slice of the currencies.
+	#[inline] pub fn currencies(&self) -> &[CurrencyCode] { &self.currency }
+	/// Gets a slice of the rates.
+	#[inline] pub fn rates(&self) -> &[RATE] { &self.rate }
+
+	/// Iterates over currency rates.
+	pub fn iter(&self) -> impl Iterator<Item = (CurrencyCode, &RATE)> {
+		self.currency.iter().copied().zip(self.rate.iter()).rev()
+	}
+
+	/// Pushes a new currency rate.
+	///
+	/// Does not check for duplicates, but other functions should
+	/// use the latest pushed rate of a currency.
+	pub fn push(&mut self, currency: CurrencyCode, rate: RATE) {
+		self.sorted &= self.currency.last().is_none_or(|&last| last <= currency);
+		self.currency.push(currency);
+		self.rate.push(rate);
+	}
+
+	/// Sets the rate of a currency, replacing its existing rate or pushing it otherwise.
+	///
+	/// Returns the replaced rate.
+	pub fn insert_or_update(&mut self, currency: CurrencyCode, rate: RATE) -> Option<RATE> {
+		if let Some(existing) = self.get_mut(currency) {
+			Some(std::mem::replace(existing, rate))
+		} else {
+			self.push(currency, rate);
+			None
+		}
+	}
+
+	/// Sorts the rates by currency, see [`Rates::sort`](crate::Rates::sort).
+	pub fn sort(&mut self) {
+		if self.sorted { return; }
+		crate::rates::sort(&mut self.currency, &mut self.rate);
+		self.sorted = true;
+	}
+
+	/// Gets whether the rates are sorted by currency, see [`RatesVec::sort`].
+	#[inline] pub const fn is_sorted(&self) -> bool { self.sorted }
+
+	/// Gets the index of the given currency's rate in [`RatesVec::currencies`] and [`RatesVec::rates`], if exists.
+	#[inline] pub fn index_of(&self, currency: CurrencyCode) -> Option<usize> {
+		crate::rates::position(&self.currency, self.sorted, currency)
+	}
+
+	/// Gets the rate for the given currency, if exists.
+	pub fn get(&self, currency: CurrencyCode) -> Option<&RATE> {
+		self.index_of(currency).map(|i| &self.rate[i])
+	}
+
+	/// Removes a currency, preserving the order of the other rates.
+	///
+	/// Returns the currency's rate, if it existed.
+	pub fn remove(&mut self, currency: CurrencyCode) -> Option<RATE> {
+		let i = self.index_of(currency)?;
+		self.currency.remove(i);
+		let rate = self.rate.remove(i);
+		while let Some(i) = self.index_of(currency) {
+			self.currency.remove(i);
+			self.rate.remove(i);
+		}
+		Some(rate)
+	}
+
+	/// Gets the rate for the given currency mutably, if exists.
+	pub fn get_mut(&mut self, currency: CurrencyCode) -> Option<&mut RATE> {
+		self.index_of(currency).map(|i| &mut self.rate[i])
+	}
+}
+
+impl<RATE> Default for RatesVec<RATE> { #[inline] fn default() -> Self { Self::new() } }
+
+impl<RATE> Extend<(CurrencyCode, RATE)> for RatesVec<RATE> {
+	fn extend<T: IntoIterator<Item = (CurrencyCode, RATE)>>(&mut self, iter: T) {
+		for (currency, rate) in iter { self.push(currency, rate); }
+	}
+}
+
+impl<RATE> FromIterator<(CurrencyCode, RATE)> for RatesVec<RATE> {
+	fn from_iter<T: IntoIterator<Item = (CurrencyCode, RATE)>>(iter: T) -> Self {
+		let mut rates = Self::new();
+		rates.extend(iter);
+		rates
+	}
+}
+
+impl<RATE> RateTable for RatesVec<RATE> {
+	type Rate = RATE;
+	#[inline] fn get(&self, currency: CurrencyCode) -> Option<&RATE> { RatesVec::get(self, currency) }
+}
+
+impl<const N: usize, RATE> From<Rates<RATE, N>> for RatesVec<RATE> {
+	fn from(rates: Rates<RATE, N>) -> Self {
+		let sorted = rates.is_sorted();
+		let (currency, rate) = rates.into_iter().rev().unzip();
+		Self { currency, rate, sorted }
+	}
+}
+
+/// Moves the rates into a [`Rates`], in order, failing with the first rate that doesn't fit.
+///
+/// Superseded rates of duplicate currencies are moved too, and take capacity.
+impl<const N: usize, RATE> TryFrom<RatesVec<RATE>> for Rates<RATE, N> {
+	type Error = CapacityError<RATE>;
+	fn try_from(rates: RatesVec<RATE>) -> Result<Self, Self::Error> {
+		Rates::try_from_iter(rates.currency.into_iter().zip(rates.rate))
+	}
+}
+
+impl<RATE: fmt::Debug> fmt::Debug for RatesVec<RATE> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_unbounded() {
+		use crate::currency::*;
+		let mut rates = ARRAY.iter().chain(&ARRAY).enumerate()
+			.map(|(i, &currency)| (currency, i as f64))
+			.collect::<RatesVec<_>>();
+		assert_eq!(rates.len(), ARRAY.len() * 2);
+		rates.sort();
+		for (i, &currency) in ARRAY.iter().enumerate() {
+			assert_eq!(rates.get(currency), Some(&((i + ARRAY.len()) as f64)));
+		}
+		assert_eq!(rates.convert(&2.0, ARRAY[1], ARRAY[1]), Some(2.0));
+		*rates.get_mut(EUR).unwrap() = -1.0;
+		assert_eq!(rates.get(EUR), Some(&-1.0));
+		assert_eq!(rates.remove(EUR), Some(-1.0));
+		assert_eq!(rates.get(EUR), None);
+		assert_eq!(rates.len(), (ARRAY.len() - 1) * 2);
+	}
+
+	#[test]
+	fn test_rates_conversion() {
+		use crate::currency::*;
+		let rates = Rates::<f64, 3>::try_from_iter([(ILS, 3.1), (EUR, 0.9), (USD, 1.0)]).unwrap();
+		let vec = RatesVec::from(rates.clone());
+		assert!(!vec.is_sorted());
+		assert_eq!(vec.currencies(), rates.currencies());
+		assert_eq!(vec.rates(), rates.rates());
+
+		let back = Rates::<f64, 3>::try_from(vec.clone()).unwrap();
+		assert!(back.eq_ordered(&rates));
+		assert!(!back.is_sorted());
+		let error = Rates::<f64, 2>::try_from(vec).unwrap_err();
+		assert_eq!((error.currency, error.rate), (USD, 1.0));
+	}
+}
@@ -0,0 +1,78 @@
+//! [`RoundableRate`]
+
+/// How to round midpoints, see [`RoundableRate`].
+#[derive(Debug, Hash, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+pub enum RoundingMode {
+	/// Rounds midpoints away from zero, e.g. 1.5 to 2 and -2.5 to -3.
+	#[default]
+	HalfUp,
+	/// Rounds midpoints to the nearest even number (banker's rounding), e.g. 1.5 to 2 and 2.5 to 2.
+	HalfEven,
+}
+
+/// Rounding to decimal places.
+pub trait RoundableRate: Sized {
+	/// Rounds to the given number of decimal places.
+	fn round_to(&self, decimal_places: u32, mode: RoundingMode) -> Self;
+}
+
+macro_rules! impl_float {
+	($($float:ty),*) => {$(
+		/// Best-effort, since floats can't represent most decimal fractions exactly.
+		///
+		/// Returns the number unchanged if scaling it to the decimal places overflows, as it then has no fraction
+		/// within those places.
+		impl RoundableRate for $float {
+			fn round_to(&self, decimal_places: u32, mode: RoundingMode) -> Self {
+				if decimal_places > <$float>::MAX_10_EXP as u32 { return *self }
+				let factor = (10 as $float).powi(decimal_places as i32);
+				let scaled = self * factor;
+				if !scaled.is_finite() { return *self }
+				let rounded = match mode {
+					RoundingMode::HalfUp => scaled.round(),
+					RoundingMode::HalfEven => scaled.round_ties_even(),
+				};
+				rounded / factor
+			}
+		}
+	)*};
+}
+impl_float!(f32, f64);
+
+#[cfg(feature = "rust_decimal")]
+impl RoundableRate for rust_decimal::Decimal {
+	fn round_to(&self, decimal_places: u32, mode: RoundingMode) -> Self {
+		use rust_decimal::RoundingStrategy;
+		self.round_dp_with_strategy(decimal_places, match mode {
+			RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+			RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_round_float() {
+		assert_eq!(1.2345f64.round_to(2, RoundingMode::HalfUp), 1.23);
+		assert_eq!(2.5f64.round_to(0, RoundingMode::HalfUp), 3.0);
+		assert_eq!(2.5f64.round_to(0, RoundingMode::HalfEven), 2.0);
+		assert_eq!((-2.5f32).round_to(0, RoundingMode::HalfUp), -3.0);
+		assert_eq!(1.2345f64.round_to(400, RoundingMode::HalfUp), 1.2345);
+		assert_eq!(1.2345f64.round_to(300, RoundingMode::HalfEven), 1.2345);
+		assert_eq!(1.2345f32.round_to(39, RoundingMode::HalfUp), 1.2345);
+		assert_eq!(1.2345f32.round_to(u32::MAX, RoundingMode::HalfUp), 1.2345);
+		assert_eq!(f64::MAX.round_to(2, RoundingMode::HalfUp), f64::MAX);
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_round_decimal() {
+		use rust_decimal::Decimal;
+		let d = |s: &str| s.parse::<Decimal>().unwrap();
+		assert_eq!(d("12.345").round_to(2, RoundingMode::HalfUp), d("12.35"));
+		assert_eq!(d("12.345").round_to(2, RoundingMode::HalfEven), d("12.34"));
+	}
+}
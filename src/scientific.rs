@@ -9,6 +9,35 @@ pub trait FromScientific: Sized {
 	///
 	/// The number representation may or may not be in scientific notation.
 	fn parse_scientific(s: &str) -> Result<Self, Self::Error>;
+
+	/// Parses a decimal number from bytes, like [`FromScientific::parse_scientific`].
+	///
+	/// Defaults to validating the bytes as UTF-8 then parsing them as a string. Override it for types that can parse
+	/// bytes directly.
+	fn parse_scientific_bytes(bytes: &[u8]) -> Result<Self, ScientificBytesError<Self::Error>> {
+		Self::parse_scientific(std::str::from_utf8(bytes)?).map_err(ScientificBytesError::Parse)
+	}
+}
+
+/// An error parsing a number from bytes, see [`FromScientific::parse_scientific_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ScientificBytesError<E> {
+	/// The bytes are not valid UTF-8.
+	#[error("the number is not valid UTF-8: {0}")]
+	Utf8(#[from] std::str::Utf8Error),
+	/// The number is invalid.
+	#[error("{0}")]
+	Parse(E),
+}
+
+impl<E> ScientificBytesError<E> {
+	/// Maps the [parse error](ScientificBytesError::Parse), keeping the [UTF-8 error](ScientificBytesError::Utf8) as is.
+	pub fn map_parse<F>(self, f: impl FnOnce(E) -> F) -> ScientificBytesError<F> {
+		match self {
+			Self::Utf8(error) => ScientificBytesError::Utf8(error),
+			Self::Parse(error) => ScientificBytesError::Parse(f(error)),
+		}
+	}
 }
 
 impl FromScientific for f64 {
@@ -29,3 +58,187 @@ impl FromScientific for rust_decimal::Decimal {
 		s.parse::<Self>().or_else(|_| Self::from_scientific(s))
 	}
 }
+
+#[cfg(feature = "bigdecimal")]
+impl FromScientific for bigdecimal::BigDecimal {
+	type Error = bigdecimal::ParseBigDecimalError;
+	fn parse_scientific(s: &str) -> Result<Self, Self::Error> { s.parse() }
+}
+
+/// An error parsing a [`fixed`] rate.
+#[cfg(feature = "fixed")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum FixedError {
+	/// The number is invalid or out of the fixed-point type's range.
+	#[error(transparent)]
+	Parse(#[from] fixed::ParseFixedError),
+	/// The exponent is invalid or too large.
+	#[error("invalid exponent")]
+	Exponent,
+}
+
+/// Rewrites a decimal number in scientific notation, e.g. `-1.5e-3`, as a plain decimal, e.g. `-0.0015`.
+#[cfg(feature = "fixed")]
+fn plain_decimal(s: &str) -> Result<std::borrow::Cow<'_, str>, FixedError> {
+	/// Beyond the range and precision of any fixed-point type, so no need to spell it out.
+	const EXPONENT_MAX: usize = 1000;
+
+	let Some((mantissa, exponent)) = s.split_once(['e', 'E']) else { return Ok(s.into()) };
+	let exponent = exponent.parse::<i32>().map_err(|_| FixedError::Exponent)?;
+	if exponent.unsigned_abs() as usize > EXPONENT_MAX { return Err(FixedError::Exponent) }
+	let (sign, mantissa) = match mantissa.strip_prefix(['-', '+']) {
+		Some(unsigned) => (&mantissa[..1], unsigned),
+		None => ("", mantissa),
+	};
+	let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+	let digits = [int, frac].concat();
+	// The position of the decimal point in the digits.
+	let point = int.len() as isize + exponent as isize;
+	Ok(if point <= 0 {
+		format!("{sign}0.{}{digits}", "0".repeat(point.unsigned_abs()))
+	} else if point as usize >= digits.len() {
+		format!("{sign}{digits}{}", "0".repeat(point as usize - digits.len()))
+	} else {
+		let (int, frac) = digits.split_at(point as usize);
+		format!("{sign}{int}.{frac}")
+	}.into())
+}
+
+macro_rules! impl_fixed {
+	($($fixed:ident: $frac:ident),*) => {$(
+		/// Parses via the number as a plain decimal, so it is rounded to the nearest representable value.
+		#[cfg(feature = "fixed")]
+		impl<Frac: fixed::types::extra::$frac> FromScientific for fixed::$fixed<Frac> {
+			type Error = FixedError;
+			fn parse_scientific(s: &str) -> Result<Self, Self::Error> { Ok(plain_decimal(s)?.parse()?) }
+		}
+	)*};
+}
+impl_fixed!(FixedI32: LeEqU32, FixedU32: LeEqU32, FixedI64: LeEqU64, FixedU64: LeEqU64, FixedI128: LeEqU128, FixedU128: LeEqU128);
+
+/// An error parsing an integer rate, see the integer [`FromScientific`] implementations.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum IntegerError {
+	/// The string is not a decimal number.
+	#[error("invalid number")]
+	Syntax,
+	/// The number has a fractional part.
+	#[error("the number is not an integer")]
+	NotIntegral,
+	/// The number is out of the integer type's range.
+	#[error("the number is out of range")]
+	Overflow,
+}
+
+/// Parses a decimal number, possibly in scientific notation, that is an integer, e.g. `1.5e3`.
+fn parse_integer(s: &str) -> Result<i128, IntegerError> {
+	let (negative, s) = match s.as_bytes().first() {
+		Some(b'-') => (true, &s[1..]),
+		Some(b'+') => (false, &s[1..]),
+		_ => (false, s),
+	};
+	let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+		Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().map_err(|_| IntegerError::Syntax)?),
+		None => (s, 0),
+	};
+	let (mut int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+	if (int.is_empty() && frac.is_empty()) || !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit()) {
+		return Err(IntegerError::Syntax);
+	}
+
+	// Trailing zeros only scale the exponent, and must be stripped for fractions like 1.50e1.
+	let trimmed = frac.trim_end_matches('0');
+	let mut exponent = i64::from(exponent) - trimmed.len() as i64;
+	let frac = trimmed;
+	if frac.is_empty() {
+		let trimmed = int.trim_end_matches('0');
+		exponent += (int.len() - trimmed.len()) as i64;
+		int = trimmed;
+	}
+
+	let mut value = 0i128;
+	for digit in int.bytes().chain(frac.bytes()) {
+		value = value.checked_mul(10).and_then(|value| value.checked_add(i128::from(digit - b'0'))).ok_or(IntegerError::Overflow)?;
+	}
+	if value == 0 { return Ok(0) }
+	if exponent < 0 { return Err(IntegerError::NotIntegral) }
+	let scale = u32::try_from(exponent).ok().and_then(|exponent| 10i128.checked_pow(exponent)).ok_or(IntegerError::Overflow)?;
+	let value = value.checked_mul(scale).ok_or(IntegerError::Overflow)?;
+	Ok(if negative { -value } else { value })
+}
+
+macro_rules! impl_integer {
+	($($int:ty),*) => {$(
+		/// Parses integral numbers only, e.g. for rates scaled to minor units, failing with
+		/// [`IntegerError::NotIntegral`] on fractions.
+		impl FromScientific for $int {
+			type Error = IntegerError;
+			fn parse_scientific(s: &str) -> Result<Self, Self::Error> {
+				parse_integer(s).and_then(|value| Self::try_from(value).map_err(|_| IntegerError::Overflow))
+			}
+		}
+	)*};
+}
+impl_integer!(i32, u32, i64, u64, i128);
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_bytes() {
+		assert_eq!(f64::parse_scientific_bytes(b"1.5e3").unwrap(), 1500.);
+		assert!(matches!(f64::parse_scientific_bytes(b"1.5e\xff"), Err(ScientificBytesError::Utf8(_))));
+		assert_eq!(i64::parse_scientific_bytes(b"1.5"), Err(ScientificBytesError::Parse(IntegerError::NotIntegral)));
+		assert_eq!(
+			i64::parse_scientific_bytes(b"1.5").map_err(|error| error.map_parse(|error| error.to_string())),
+			Err(ScientificBytesError::Parse(IntegerError::NotIntegral.to_string())),
+		);
+		assert!(matches!(f64::parse_scientific_bytes(b"\xff").map_err(|error| error.map_parse(|_| ())), Err(ScientificBytesError::Utf8(_))));
+	}
+
+	#[test]
+	fn test_integer() {
+		assert_eq!(i64::parse_scientific("1500"), Ok(1500));
+		assert_eq!(i64::parse_scientific("1.5e3"), Ok(1500));
+		assert_eq!(i64::parse_scientific("1.50E+3"), Ok(1500));
+		assert_eq!(i64::parse_scientific("-25e-1"), Err(IntegerError::NotIntegral));
+		assert_eq!(i64::parse_scientific("-250e-1"), Ok(-25));
+		assert_eq!(u64::parse_scientific("0.0"), Ok(0));
+		assert_eq!(u64::parse_scientific("0e-9"), Ok(0));
+		assert_eq!(i128::parse_scientific("1e30"), Ok(10i128.pow(30)));
+		assert_eq!(i64::parse_scientific("1.23"), Err(IntegerError::NotIntegral));
+		assert_eq!(i64::parse_scientific("1e30"), Err(IntegerError::Overflow));
+		assert_eq!(u64::parse_scientific("-1"), Err(IntegerError::Overflow));
+		for invalid in ["", ".", "e3", "1e", "1.2.3", "one", "--1", "1 "] {
+			assert_eq!(i64::parse_scientific(invalid), Err(IntegerError::Syntax), "{invalid:?}");
+		}
+	}
+
+	#[cfg(feature = "fixed")]
+	#[test]
+	fn test_fixed() {
+		use fixed::types::{I32F32, I64F64, U64F64};
+		assert_eq!(I64F64::parse_scientific("1.5e3"), Ok(I64F64::from_num(1500)));
+		assert_eq!(I64F64::parse_scientific("-1.5E-3"), Ok("-0.0015".parse::<I64F64>().unwrap()));
+		assert_eq!(I64F64::parse_scientific("25e-1"), Ok(I64F64::from_num(2.5)));
+		assert_eq!(U64F64::parse_scientific("3.25"), Ok(U64F64::from_num(3.25)));
+		assert_eq!(I32F32::parse_scientific("12.5e+1"), Ok(I32F32::from_num(125)));
+		assert!(matches!(I32F32::parse_scientific("1e30"), Err(FixedError::Parse(_))));
+		assert!(matches!(I32F32::parse_scientific("1e99999"), Err(FixedError::Exponent)));
+		assert!(matches!(I32F32::parse_scientific("1ee3"), Err(FixedError::Exponent)));
+		assert!(matches!(I32F32::parse_scientific("1.2.3"), Err(FixedError::Parse(_))));
+	}
+
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn test_bigdecimal() {
+		use bigdecimal::BigDecimal;
+		let d = |s: &str| s.parse::<BigDecimal>().unwrap();
+		assert_eq!(BigDecimal::parse_scientific("1.5e3"), Ok(d("1500")));
+		assert_eq!(BigDecimal::parse_scientific("0.000012345678901234567890123456789"), Ok(d("12345678901234567890123456789e-33")));
+		// beyond rust_decimal's range
+		assert_eq!(BigDecimal::parse_scientific("1.23e40"), Ok(d("123") * d("1e38")));
+		assert!(BigDecimal::parse_scientific("1.2.3").is_err());
+	}
+}
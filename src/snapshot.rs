@@ -0,0 +1,83 @@
+//! [`Snapshot`]
+
+use std::{fmt, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{latest::Metadata, CurrencyCode, FromScientific, Rates};
+
+/// Fetched [`Rates`] together with when they were last updated, e.g. to persist and fall back to when offline.
+///
+/// Construct one from the output of a fetch, e.g. [`Client`](crate::Client)'s, with [`From`]:
+/// ```ignore
+/// let snapshot = Snapshot::from(client.latest().fetch::<f64, 200>().await?);
+/// snapshot.write_json(File::create("rates.json")?)?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+	serialize = "RATE: Serialize",
+	deserialize = "RATE: Deserialize<'de> + FromScientific, <RATE as FromScientific>::Error: fmt::Display",
+))]
+pub struct Snapshot<RATE, const N: usize = { crate::currency::ARRAY.len() + /* slack */ 10 }> {
+	/// When the rates were last updated, see [`Metadata::last_updated_at`].
+	pub last_updated_at: String,
+	/// The base currency of the rates, or [`None`] for the API's default base currency ([`USD`](crate::currency::USD)).
+	pub base: Option<CurrencyCode>,
+	/// The rates.
+	pub rates: Rates<RATE, N>,
+}
+
+impl<const N: usize, RATE> Snapshot<RATE, N> {
+	/// Creates a new [`Snapshot`].
+	#[inline] pub fn new(rates: Rates<RATE, N>, last_updated_at: String, base: Option<CurrencyCode>) -> Self {
+		Self { last_updated_at, base, rates }
+	}
+
+	/// Sets the base currency, see [`Snapshot::base`].
+	#[inline] pub fn with_base(mut self, base: CurrencyCode) -> Self { self.base = Some(base); self }
+
+	/// Writes the snapshot as JSON.
+	pub fn write_json(&self, writer: impl io::Write) -> serde_json::Result<()> where RATE: Serialize {
+		serde_json::to_writer(writer, self)
+	}
+
+	/// Reads a snapshot from JSON, see [`Snapshot::write_json`].
+	///
+	/// Fails on invalid currency codes, or on more than `N` currencies.
+	pub fn read_json(reader: impl io::Read) -> serde_json::Result<Self>
+	where RATE: for<'de> Deserialize<'de> + FromScientific<Error: fmt::Display> {
+		serde_json::from_reader(reader)
+	}
+}
+
+/// Creates a snapshot from fetched rates, with the default base currency, see [`Snapshot::with_base`].
+impl<const N: usize, RATE, RateLimit> From<(Rates<RATE, N>, Metadata<String, RateLimit>)> for Snapshot<RATE, N> {
+	#[inline] fn from((rates, metadata): (Rates<RATE, N>, Metadata<String, RateLimit>)) -> Self {
+		Self::new(rates, metadata.last_updated_at, None)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::currency::*;
+
+	#[test]
+	fn test_snapshot_json() {
+		let rates = Rates::<f64, 2>::try_from_iter([(USD, 1.1), (EUR, 1.0)]).unwrap();
+		let snapshot = Snapshot::new(rates, "2023-06-21T23:59:59Z".into(), Some(EUR));
+		let mut json = Vec::new();
+		snapshot.write_json(&mut json).unwrap();
+		assert_eq!(
+			String::from_utf8(json.clone()).unwrap(),
+			r#"{"last_updated_at":"2023-06-21T23:59:59Z","base":"EUR","rates":{"EUR":1.0,"USD":1.1}}"#,
+		);
+		assert_eq!(Snapshot::<f64, 2>::read_json(json.as_slice()).unwrap(), snapshot);
+
+		let error = Snapshot::<f64, 1>::read_json(json.as_slice()).unwrap_err();
+		assert!(error.to_string().contains("up to 1 currency rates"), "{error}");
+		let error = Snapshot::<f64, 2>::read_json(r#"{"last_updated_at":"","base":null,"rates":{"E1":1}}"#.as_bytes()).unwrap_err();
+		assert!(error.to_string().contains("invalid currency code character"), "{error}");
+		assert!(Snapshot::<f64, 2>::read_json(r#"{"rates":{}}"#.as_bytes()).is_err());
+	}
+}
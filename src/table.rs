@@ -0,0 +1,129 @@
+//! [`RateTable`]
+
+use std::{iter::Sum, ops::{Div, Mul}};
+
+use crate::{ArithmeticError, CheckedRateOps, CurrencyCode, Money, RoundableRate, RoundingMode};
+
+/// A table of currency rates, implemented by [`Rates`](crate::Rates) and [`RatesVec`](crate::RatesVec).
+///
+/// Provides the operations on rates that only require looking them up, such as [conversion](RateTable::convert).
+pub trait RateTable {
+	/// The rate type.
+	type Rate;
+
+	/// Gets the rate for the given currency, if exists.
+	fn get(&self, currency: CurrencyCode) -> Option<&Self::Rate>;
+
+	/// Gets whether the given currency has a rate.
+	#[inline] fn contains(&self, currency: CurrencyCode) -> bool { self.get(currency).is_some() }
+
+	/// Gets the rate for the given currency code, if it is valid and exists.
+	#[inline] fn get_by_str(&self, currency: &str) -> Option<&Self::Rate> { self.get(currency.parse().ok()?) }
+
+	/// Gets the conversion factor between currencies, i.e. the amount of `to` that one `from` is worth.
+	///
+	/// Returns [`None`] if either the `from` or `to` currencies are missing.
+	fn ratio(&self, from: CurrencyCode, to: CurrencyCode) -> Option<Self::Rate>
+	where for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate> {
+		let from_value = self.get(from)?;
+		let to_value = self.get(to)?;
+		Some(to_value / from_value)
+	}
+
+	/// Gets the inverse of a currency's rate, i.e. the value of one unit of the currency in the base currency.
+	///
+	/// Returns [`None`] if the currency is missing or its rate is zero.
+	fn reciprocal(&self, currency: CurrencyCode) -> Option<Self::Rate>
+	where Self::Rate: From<u8> + PartialEq, for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate> {
+		let rate = self.get(currency)?;
+		(*rate != Self::Rate::from(0)).then(|| &Self::Rate::from(1) / rate)
+	}
+
+	/// Converts an amount between currencies.
+	///
+	/// Returns [`None`] if either the `from` or `to` currencies are missing.
+	fn convert(&self, amount: &Self::Rate, from: CurrencyCode, to: CurrencyCode) -> Option<Self::Rate>
+	where for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<Self::Rate, Output = Self::Rate> {
+		self.ratio(from, to).map(|ratio| amount * ratio)
+	}
+
+	/// Converts an amount between currencies, failing with the reason it couldn't.
+	///
+	/// Fails if either currency is missing, or if the `from` rate is zero or NaN.
+	fn try_convert(&self, amount: &Self::Rate, from: CurrencyCode, to: CurrencyCode) -> Result<Self::Rate, ConvertError>
+	where Self::Rate: From<u8> + PartialEq, for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<Self::Rate, Output = Self::Rate> {
+		let from_value = self.get(from).ok_or(ConvertError::MissingFrom(from))?;
+		let to_value = self.get(to).ok_or(ConvertError::MissingTo(to))?;
+		#[allow(clippy::eq_op)] // a rate that isn't equal to itself is NaN
+		if *from_value == Self::Rate::from(0) || from_value != from_value {
+			return Err(ConvertError::InvalidRate(from));
+		}
+		Ok(amount * (to_value / from_value))
+	}
+
+	/// Converts money to another currency, like [`RateTable::try_convert`].
+	fn convert_money(&self, money: Money<Self::Rate>, to: CurrencyCode) -> Result<Money<Self::Rate>, ConvertError>
+	where Self::Rate: From<u8> + PartialEq, for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<Self::Rate, Output = Self::Rate> {
+		Ok(Money::new(self.try_convert(&money.amount, money.currency, to)?, to))
+	}
+
+	/// Converts an amount between currencies, like [`RateTable::try_convert`], failing rather than panicking or
+	/// producing a non-finite result on arithmetic errors, e.g. an overflowing `Decimal` amount.
+	fn checked_convert(&self, amount: &Self::Rate, from: CurrencyCode, to: CurrencyCode) -> Result<Self::Rate, ConvertError>
+	where Self::Rate: CheckedRateOps {
+		let from_value = self.get(from).ok_or(ConvertError::MissingFrom(from))?;
+		let to_value = self.get(to).ok_or(ConvertError::MissingTo(to))?;
+		Ok(amount.try_mul(&to_value.try_div(from_value)?)?)
+	}
+
+	/// Converts many amounts between the same currencies, like [`RateTable::convert`], looking up their rates once.
+	///
+	/// Returns [`None`] if either the `from` or `to` currencies are missing.
+	fn convert_many(&self, amounts: &[Self::Rate], from: CurrencyCode, to: CurrencyCode) -> Option<Vec<Self::Rate>>
+	where for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<&'x Self::Rate, Output = Self::Rate> {
+		let ratio = self.ratio(from, to)?;
+		Some(amounts.iter().map(|amount| amount * &ratio).collect())
+	}
+
+	/// Sums amounts of various currencies, e.g. a portfolio's holdings, in the `target` currency.
+	///
+	/// Returns [`None`] if the `target` or any of the holdings' currencies are missing.
+	fn total(&self, holdings: impl IntoIterator<Item = (CurrencyCode, Self::Rate)>, target: CurrencyCode) -> Option<Self::Rate>
+	where Self::Rate: Sum, for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<Self::Rate, Output = Self::Rate> {
+		if !self.contains(target) { return None }
+		holdings.into_iter().map(|(currency, amount)| self.convert(&amount, currency, target)).sum()
+	}
+
+	/// Converts an amount between currencies, like [`RateTable::convert`], rounding the result to `decimal_places`.
+	fn convert_rounded(&self, amount: &Self::Rate, from: CurrencyCode, to: CurrencyCode, decimal_places: u32, mode: RoundingMode) -> Option<Self::Rate>
+	where Self::Rate: RoundableRate, for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<Self::Rate, Output = Self::Rate> {
+		self.convert(amount, from, to).map(|converted| converted.round_to(decimal_places, mode))
+	}
+
+	/// Converts an amount between currencies, like [`RateTable::convert`], rounding the result to the `to` currency's
+	/// [minor units](CurrencyCode::decimal_places), e.g. 10 USD to `1497` JPY rather than `1497.38221`.
+	///
+	/// Most currencies have 2 decimal places, but some have 0 (e.g. JPY, KRW, CLP), 3 (e.g. BHD, KWD, JOD, TND) or, for
+	/// cryptocurrencies, 8 or more (e.g. 8 for BTC and 18 for ETH).
+	fn convert_rounded_minor(&self, amount: &Self::Rate, from: CurrencyCode, to: CurrencyCode, mode: RoundingMode) -> Option<Self::Rate>
+	where Self::Rate: RoundableRate, for<'x> &'x Self::Rate: Div<&'x Self::Rate, Output = Self::Rate>, for<'x> &'x Self::Rate: Mul<Self::Rate, Output = Self::Rate> {
+		self.convert_rounded(amount, from, to, to.decimal_places().into(), mode)
+	}
+}
+
+/// An error converting between currencies, see [`RateTable::try_convert`].
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ConvertError {
+	/// The currency converted from has no rate.
+	#[error("missing {0} rate to convert from")]
+	MissingFrom(CurrencyCode),
+	/// The currency converted to has no rate.
+	#[error("missing {0} rate to convert to")]
+	MissingTo(CurrencyCode),
+	/// The rate of the currency converted from is zero or NaN.
+	#[error("invalid {0} rate to convert from")]
+	InvalidRate(CurrencyCode),
+	/// The conversion failed arithmetically, see [`RateTable::checked_convert`].
+	#[error(transparent)]
+	Arithmetic(#[from] ArithmeticError),
+}
@@ -0,0 +1,60 @@
+//! [`Token`]
+
+use std::{borrow::Cow, convert::Infallible, ffi::OsStr, fmt, str::FromStr};
+
+/// An [API token](https://currencyapi.com/docs/#authentication-api-key-information).
+///
+/// Its [`Debug`] and [`Display`](fmt::Display) implementations redact the token, so it doesn't leak into logs.
+///
+/// With the `zeroize` feature, an owned token is zeroed out when dropped.
+#[derive(Clone, Default, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Token<'a>(Cow<'a, str>);
+
+impl<'a> Token<'a> {
+	/// Creates a new [`Token`].
+	#[inline] pub fn new(token: impl Into<Cow<'a, str>>) -> Self { Self(token.into()) }
+
+	/// Gets the token value.
+	#[inline] pub fn expose(&self) -> &str { &self.0 }
+
+	/// Borrows the token.
+	#[inline] pub fn borrowed(&self) -> Token<'_> { Token(Cow::Borrowed(&self.0)) }
+
+	/// Takes the token value, leaving an empty token in its place.
+	#[inline] pub(crate) fn take(&mut self) -> Cow<'a, str> { std::mem::take(&mut self.0) }
+}
+
+impl Token<'static> {
+	/// Reads the token from the given environment variable, e.g. `CURRENCYAPI_KEY`.
+	#[inline] pub fn from_env(key: impl AsRef<OsStr>) -> Result<Self, std::env::VarError> {
+		std::env::var(key).map(Self::from)
+	}
+}
+
+impl From<String> for Token<'static> {
+	#[inline] fn from(token: String) -> Self { Self(Cow::Owned(token)) }
+}
+
+impl<'a> From<&'a str> for Token<'a> {
+	#[inline] fn from(token: &'a str) -> Self { Self(Cow::Borrowed(token)) }
+}
+
+impl FromStr for Token<'static> {
+	type Err = Infallible;
+	#[inline] fn from_str(s: &str) -> Result<Self, Self::Err> { Ok(Self::from(s.to_owned())) }
+}
+
+impl fmt::Debug for Token<'_> {
+	#[inline] fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("Token(***)") }
+}
+
+impl fmt::Display for Token<'_> {
+	#[inline] fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Token<'_> {
+	fn drop(&mut self) {
+		if let Cow::Owned(token) = &mut self.0 { zeroize::Zeroize::zeroize(token); }
+	}
+}
@@ -4,7 +4,8 @@ use std::io;
 
 pub mod capacity {
 	// const ISO8601_LEN_MAX: usize = 30;
-	const CURRENCIES_MAX_CAPACITY: usize = (crate::currency::ARRAY.len() + /* slack */ 10) * 4 - 1;
+	/// The URL capacity for the currencies list, where each (3-letter) currency costs 4 bytes with its separator.
+	pub const CURRENCIES_MAX_CAPACITY: usize = (crate::currency::ARRAY.len() + /* slack */ 10) * 4 - 1;
 
 	// pub const URL_CAPACITY_STATUS: usize = "https://api.currencyapi.com/v3/status".len();
 	// pub const URL_CAPACITY_CURRENCIES: usize = "https://api.currencyapi.com/v3/currencies?currencies=".len() + CURRENCIES_MAX_CAPACITY;
@@ -56,7 +57,6 @@ pub mod base {
 		}
 	}
 }
-pub use base::*;
 
 mod base_currency {
 	use crate::CurrencyCode;
@@ -64,14 +64,16 @@ mod base_currency {
 	use super::UrlPart;
 
 	/// A base currency parameter for [`Builder`].
+	#[derive(Debug, Hash, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
 	pub struct BaseCurrency<T>(pub T);
 
 	/// A type for [`Builder`] indicating the request does not specify a base currency parameter.
+	#[derive(Debug, Hash, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
 	pub struct NoBaseCurrency;
 
 	impl UrlPart for NoBaseCurrency {}
 
-	impl<'a> UrlPart for BaseCurrency<&'a str> {
+	impl UrlPart for BaseCurrency<&str> {
 		#[inline] fn write_url_part(self, mut write: impl std::io::Write, prefix: &[u8]) -> std::io::Result<bool> {
 			write.write_all(prefix)?;
 			write.write_all(b"base_currency=")?;
@@ -80,7 +82,7 @@ mod base_currency {
 		}
 	}
 
-	impl<'a> UrlPart for BaseCurrency<CurrencyCode> {
+	impl UrlPart for BaseCurrency<CurrencyCode> {
 		#[inline] fn write_url_part(self, write: impl std::io::Write, prefix: &[u8]) -> std::io::Result<bool> {
 			BaseCurrency(self.0.as_ref()).write_url_part(write, prefix)
 		}